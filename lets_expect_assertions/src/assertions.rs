@@ -1,9 +1,14 @@
 pub use super::bool::*;
 pub use super::change::*;
+pub use super::collection::*;
+pub use super::combinators::*;
 pub use super::equality::*;
+pub use super::float::*;
 pub use super::iterator::*;
 pub use super::match_pattern::*;
 pub use super::option::*;
 pub use super::panic::*;
 pub use super::partial_ord::*;
 pub use super::result::*;
+pub use super::snapshot::*;
+pub use super::string::*;
@@ -66,11 +66,146 @@ where
     }
 }
 
+pub fn increase<R>(from: &R, to: &R) -> AssertionResult
+where
+    R: Debug + PartialOrd,
+{
+    if to > from {
+        Ok(())
+    } else {
+        let from = format!("{:?}", from).red().bold();
+        let to = format!("{:?}", to).red().bold();
+        Err(AssertionError {
+            message: vec![format!(
+                "Expected to increase, but it changed from {} to {}",
+                from, to
+            )],
+        })
+    }
+}
+
+pub fn decrease<R>(from: &R, to: &R) -> AssertionResult
+where
+    R: Debug + PartialOrd,
+{
+    if to < from {
+        Ok(())
+    } else {
+        let from = format!("{:?}", from).red().bold();
+        let to = format!("{:?}", to).red().bold();
+        Err(AssertionError {
+            message: vec![format!(
+                "Expected to decrease, but it changed from {} to {}",
+                from, to
+            )],
+        })
+    }
+}
+
+pub fn by_at_least<R, D>(expected: D) -> impl Fn(&R, &R) -> AssertionResult
+where
+    R: Sub<Output = D> + Clone,
+    D: Debug + PartialOrd,
+{
+    move |from, to| {
+        let diff = to.clone() - from.clone();
+        if diff >= expected {
+            Ok(())
+        } else {
+            let expected = format!("{:?}", expected).green().bold();
+            let diff = format!("{:?}", diff).red().bold();
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected to change by at least {}, but it was changed by {} instead",
+                    expected, diff
+                )],
+            })
+        }
+    }
+}
+
+pub fn by_at_most<R, D>(expected: D) -> impl Fn(&R, &R) -> AssertionResult
+where
+    R: Sub<Output = D> + Clone,
+    D: Debug + PartialOrd,
+{
+    move |from, to| {
+        let diff = to.clone() - from.clone();
+        if diff <= expected {
+            Ok(())
+        } else {
+            let expected = format!("{:?}", expected).green().bold();
+            let diff = format!("{:?}", diff).red().bold();
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected to change by at most {}, but it was changed by {} instead",
+                    expected, diff
+                )],
+            })
+        }
+    }
+}
+
+pub fn by_between<R, D>(lower: D, upper: D) -> impl Fn(&R, &R) -> AssertionResult
+where
+    R: Sub<Output = D> + Clone,
+    D: Debug + PartialOrd,
+{
+    move |from, to| {
+        let diff = to.clone() - from.clone();
+        if lower <= diff && diff <= upper {
+            Ok(())
+        } else {
+            let lower = format!("{:?}", lower).green().bold();
+            let upper = format!("{:?}", upper).green().bold();
+            let diff = format!("{:?}", diff).red().bold();
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected to change by between {} and {}, but it was changed by {} instead",
+                    lower, upper, diff
+                )],
+            })
+        }
+    }
+}
+
+pub fn by_approximately<R, D>(expected: D, tolerance: D) -> impl Fn(&R, &R) -> AssertionResult
+where
+    R: Sub<Output = D> + Clone,
+    D: Debug + PartialOrd + Sub<Output = D> + Clone,
+{
+    move |from, to| {
+        let diff = to.clone() - from.clone();
+        // Subtracting the smaller value from the larger one keeps unsigned types like `Duration` from underflowing.
+        let distance = if diff > expected {
+            diff.clone() - expected.clone()
+        } else {
+            expected.clone() - diff.clone()
+        };
+
+        if distance <= tolerance {
+            Ok(())
+        } else {
+            let expected = format!("{:?}", expected).green().bold();
+            let tolerance = format!("{:?}", tolerance).green().bold();
+            let diff = format!("{:?}", diff).red().bold();
+            let distance = format!("{:?}", distance).red().bold();
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected to change by {} ± {}, but it was changed by {} instead, a difference of {}",
+                    expected, tolerance, diff, distance
+                )],
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::expected_err::expected_err;
     use colored::control::set_override;
+    use std::time::{Duration, Instant};
 
     #[test]
     fn test_from_ok() {
@@ -115,4 +250,113 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn test_increase_ok() {
+        assert_eq!(increase(&1, &5), Ok(()));
+    }
+
+    #[test]
+    fn test_increase_err() {
+        set_override(false);
+        assert_eq!(
+            increase(&2, &2),
+            expected_err(vec!["Expected to increase, but it changed from 2 to 2"])
+        );
+    }
+
+    #[test]
+    fn test_decrease_ok() {
+        assert_eq!(decrease(&5, &1), Ok(()));
+    }
+
+    #[test]
+    fn test_decrease_err() {
+        set_override(false);
+        assert_eq!(
+            decrease(&1, &3),
+            expected_err(vec!["Expected to decrease, but it changed from 1 to 3"])
+        );
+    }
+
+    #[test]
+    fn test_by_at_least_ok() {
+        assert_eq!(by_at_least(2)(&1, &4), Ok(()));
+    }
+
+    #[test]
+    fn test_by_at_least_err() {
+        set_override(false);
+        assert_eq!(
+            by_at_least(2)(&1, &2),
+            expected_err(vec![
+                "Expected to change by at least 2, but it was changed by 1 instead"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_by_at_least_instant() {
+        let from = Instant::now();
+        let to = from + Duration::from_secs(2);
+        assert_eq!(by_at_least(Duration::from_secs(1))(&from, &to), Ok(()));
+    }
+
+    #[test]
+    fn test_by_at_most_ok() {
+        assert_eq!(by_at_most(2)(&1, &3), Ok(()));
+    }
+
+    #[test]
+    fn test_by_at_most_err() {
+        set_override(false);
+        assert_eq!(
+            by_at_most(2)(&1, &4),
+            expected_err(vec![
+                "Expected to change by at most 2, but it was changed by 3 instead"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_by_between_ok() {
+        assert_eq!(by_between(1, 3)(&1, &3), Ok(()));
+    }
+
+    #[test]
+    fn test_by_between_err() {
+        set_override(false);
+        assert_eq!(
+            by_between(1, 3)(&1, &1),
+            expected_err(vec![
+                "Expected to change by between 1 and 3, but it was changed by 0 instead"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_by_approximately_ok() {
+        assert_eq!(by_approximately(1.0, 0.01)(&0.1, &1.105), Ok(()));
+    }
+
+    #[test]
+    fn test_by_approximately_duration() {
+        let from = Instant::now();
+        let to = from + Duration::from_millis(990);
+        assert_eq!(
+            by_approximately(Duration::from_secs(1), Duration::from_millis(20))(&from, &to),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_by_approximately_err() {
+        set_override(false);
+        assert_eq!(
+            by_approximately(10, 2)(&0, &5),
+            expected_err(vec![
+                "Expected to change by 10 ± 2, but it was changed by 5 instead, a difference of 5"
+            ])
+        );
+    }
 }
@@ -0,0 +1,450 @@
+use colored::Colorize;
+use lets_expect_core::assertions::{
+    assertion_error::AssertionError, assertion_result::AssertionResult,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque},
+    fmt::Debug,
+    hash::BuildHasher,
+};
+
+/// Compares an element yielded when iterating over a collection by reference with an expected value.
+pub trait ElementEq<E> {
+    fn element_eq(&self, expected: &E) -> bool;
+}
+
+impl<T, E> ElementEq<E> for &T
+where
+    T: PartialEq<E> + ?Sized,
+{
+    fn element_eq(&self, expected: &E) -> bool {
+        **self == *expected
+    }
+}
+
+impl<K, V, EK, EV> ElementEq<(EK, EV)> for (&K, &V)
+where
+    K: PartialEq<EK>,
+    V: PartialEq<EV>,
+{
+    fn element_eq(&self, expected: &(EK, EV)) -> bool {
+        *self.0 == expected.0 && *self.1 == expected.1
+    }
+}
+
+/// Values `contain` can look for an element in.
+pub trait Container<E> {
+    fn contains_element(&self, expected: &E) -> bool;
+}
+
+impl<C, E> Container<E> for &C
+where
+    C: Container<E> + ?Sized,
+{
+    fn contains_element(&self, expected: &E) -> bool {
+        (**self).contains_element(expected)
+    }
+}
+
+macro_rules! impl_container {
+    ($($collection:ident),+) => {
+        $(
+            impl<T, E> Container<E> for $collection<T>
+            where
+                T: PartialEq<E>,
+            {
+                fn contains_element(&self, expected: &E) -> bool {
+                    self.iter().any(|element| element == expected)
+                }
+            }
+        )+
+    };
+}
+
+impl_container!(Vec, VecDeque, LinkedList, BTreeSet, BinaryHeap);
+
+impl<T, E> Container<E> for [T]
+where
+    T: PartialEq<E>,
+{
+    fn contains_element(&self, expected: &E) -> bool {
+        self.iter().any(|element| element == expected)
+    }
+}
+
+impl<T, E, const N: usize> Container<E> for [T; N]
+where
+    T: PartialEq<E>,
+{
+    fn contains_element(&self, expected: &E) -> bool {
+        self.iter().any(|element| element == expected)
+    }
+}
+
+impl<T, E, S> Container<E> for HashSet<T, S>
+where
+    T: PartialEq<E>,
+    S: BuildHasher,
+{
+    fn contains_element(&self, expected: &E) -> bool {
+        self.iter().any(|element| element == expected)
+    }
+}
+
+impl<K, V, EK, EV, S> Container<(EK, EV)> for HashMap<K, V, S>
+where
+    K: PartialEq<EK>,
+    V: PartialEq<EV>,
+    S: BuildHasher,
+{
+    fn contains_element(&self, expected: &(EK, EV)) -> bool {
+        self.iter().any(|element| element.element_eq(expected))
+    }
+}
+
+impl<K, V, EK, EV> Container<(EK, EV)> for BTreeMap<K, V>
+where
+    K: PartialEq<EK>,
+    V: PartialEq<EV>,
+{
+    fn contains_element(&self, expected: &(EK, EV)) -> bool {
+        self.iter().any(|element| element.element_eq(expected))
+    }
+}
+
+pub fn contain<R, E>(expected: E) -> impl Fn(&R) -> AssertionResult
+where
+    R: Container<E> + Debug + ?Sized,
+    E: Debug,
+{
+    move |received| {
+        if received.contains_element(&expected) {
+            Ok(())
+        } else {
+            let expected = format!("{:?}", expected).green().bold();
+            let received = format!("{:?}", received).red().bold();
+            Err(AssertionError {
+                message: vec![format!("Expected {} to contain {}", received, expected)],
+            })
+        }
+    }
+}
+
+/// Collections whose elements can be compared with a list of expected elements.
+/// Implemented for everything that can be iterated over by reference.
+pub trait Elements<E> {
+    /// Returns the expected elements missing from the collection
+    /// and the elements of the collection which were not expected.
+    fn difference<'a>(&self, expected: &'a [E]) -> (Vec<&'a E>, Vec<String>);
+    /// Returns the index of the first element which differs from the expected one.
+    fn first_difference(&self, expected: &[E]) -> Option<usize>;
+}
+
+impl<R, E> Elements<E> for R
+where
+    R: ?Sized,
+    for<'a> &'a R: IntoIterator,
+    for<'a> <&'a R as IntoIterator>::Item: ElementEq<E> + Debug,
+{
+    fn difference<'a>(&self, expected: &'a [E]) -> (Vec<&'a E>, Vec<String>) {
+        let mut unexpected = self.into_iter().collect::<Vec<_>>();
+        let mut missing = Vec::new();
+
+        for element in expected {
+            match unexpected
+                .iter()
+                .position(|received| received.element_eq(element))
+            {
+                Some(position) => {
+                    unexpected.remove(position);
+                }
+                None => missing.push(element),
+            }
+        }
+
+        let unexpected = unexpected
+            .iter()
+            .map(|element| format!("{:?}", element))
+            .collect();
+
+        (missing, unexpected)
+    }
+
+    fn first_difference(&self, expected: &[E]) -> Option<usize> {
+        let mut elements = self.into_iter();
+
+        (0..=expected.len()).find(|index| match (elements.next(), expected.get(*index)) {
+            (Some(element), Some(expected)) => !element.element_eq(expected),
+            (None, None) => false,
+            _ => true,
+        })
+    }
+}
+
+fn difference_lines<E: Debug>(missing: &[&E], unexpected: &[String]) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    if !missing.is_empty() {
+        lines.push(format!(
+            "Missing: {}",
+            format!("{:?}", missing).green().bold()
+        ));
+    }
+    if !unexpected.is_empty() {
+        lines.push(format!(
+            "Unexpected: {}",
+            format!("[{}]", unexpected.join(", ")).red().bold()
+        ));
+    }
+
+    lines
+}
+
+pub fn contain_all_of<R, E, I>(expected: I) -> impl Fn(&R) -> AssertionResult
+where
+    R: Elements<E> + Debug + ?Sized,
+    E: Debug,
+    I: IntoIterator<Item = E>,
+{
+    let expected = expected.into_iter().collect::<Vec<E>>();
+
+    move |received| {
+        let (missing, _) = received.difference(&expected);
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            let mut message = vec![format!(
+                "Expected {} to contain all of {}",
+                format!("{:?}", received).red().bold(),
+                format!("{:?}", expected).green().bold()
+            )];
+            message.extend(difference_lines(&missing, &[]));
+            Err(AssertionError { message })
+        }
+    }
+}
+
+pub fn contain_exactly<R, E, I>(expected: I) -> impl Fn(&R) -> AssertionResult
+where
+    R: Elements<E> + Debug + ?Sized,
+    E: Debug,
+    I: IntoIterator<Item = E>,
+{
+    let expected = expected.into_iter().collect::<Vec<E>>();
+
+    move |received| match received.first_difference(&expected) {
+        None => Ok(()),
+        Some(index) => {
+            let mut message = vec![format!(
+                "Expected {} to contain exactly {}",
+                format!("{:?}", received).red().bold(),
+                format!("{:?}", expected).green().bold()
+            )];
+
+            let (missing, unexpected) = received.difference(&expected);
+            if missing.is_empty() && unexpected.is_empty() {
+                message.push(format!(
+                    "Elements are in a different order, first difference at index {}",
+                    index
+                ));
+            } else {
+                message.extend(difference_lines(&missing, &unexpected));
+            }
+
+            Err(AssertionError { message })
+        }
+    }
+}
+
+pub fn contain_in_any_order<R, E, I>(expected: I) -> impl Fn(&R) -> AssertionResult
+where
+    R: Elements<E> + Debug + ?Sized,
+    E: Debug,
+    I: IntoIterator<Item = E>,
+{
+    let expected = expected.into_iter().collect::<Vec<E>>();
+
+    move |received| {
+        let (missing, unexpected) = received.difference(&expected);
+
+        if missing.is_empty() && unexpected.is_empty() {
+            Ok(())
+        } else {
+            let mut message = vec![format!(
+                "Expected {} to contain {} in any order",
+                format!("{:?}", received).red().bold(),
+                format!("{:?}", expected).green().bold()
+            )];
+            message.extend(difference_lines(&missing, &unexpected));
+            Err(AssertionError { message })
+        }
+    }
+}
+
+pub fn be_empty<R>(received: &R) -> AssertionResult
+where
+    R: Debug + ?Sized,
+    for<'a> &'a R: IntoIterator,
+{
+    if received.into_iter().next().is_none() {
+        Ok(())
+    } else {
+        let received = format!("{:?}", received).red().bold();
+        Err(AssertionError {
+            message: vec![format!("Expected {} to be empty", received)],
+        })
+    }
+}
+
+pub fn have_len<R>(expected: usize) -> impl Fn(&R) -> AssertionResult
+where
+    R: Debug + ?Sized,
+    for<'a> &'a R: IntoIterator,
+{
+    move |received| {
+        let len = received.into_iter().count();
+
+        if len == expected {
+            Ok(())
+        } else {
+            let received = format!("{:?}", received).red().bold();
+            let expected = expected.to_string().green().bold();
+            let len = len.to_string().red().bold();
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected {} to have length {}, but it has {}",
+                    received, expected, len
+                )],
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected_err::expected_err;
+    use colored::control::set_override;
+
+    #[test]
+    fn test_contain_ok() {
+        assert_eq!(contain(2)(&vec![1, 2, 3]), Ok(()));
+        assert_eq!(contain("b")(&HashSet::from(["a", "b"])), Ok(()));
+        assert_eq!(contain((1, "a"))(&BTreeMap::from([(1, "a")])), Ok(()));
+    }
+
+    #[test]
+    fn test_contain_err() {
+        set_override(false);
+        assert_eq!(
+            contain(4)(&[1, 2, 3][..]),
+            expected_err(vec!["Expected [1, 2, 3] to contain 4"])
+        );
+    }
+
+    #[test]
+    fn test_contain_all_of_ok() {
+        assert_eq!(contain_all_of([3, 1])(&vec![1, 2, 3]), Ok(()));
+    }
+
+    #[test]
+    fn test_contain_all_of_err() {
+        set_override(false);
+        assert_eq!(
+            contain_all_of([1, 4, 5])(&vec![1, 2, 3]),
+            expected_err(vec![
+                "Expected [1, 2, 3] to contain all of [1, 4, 5]",
+                "Missing: [4, 5]"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_contain_exactly_ok() {
+        assert_eq!(contain_exactly([1, 2, 3])(&vec![1, 2, 3]), Ok(()));
+    }
+
+    #[test]
+    fn test_contain_exactly_err() {
+        set_override(false);
+        assert_eq!(
+            contain_exactly([1, 2, 4])(&vec![1, 2, 3]),
+            expected_err(vec![
+                "Expected [1, 2, 3] to contain exactly [1, 2, 4]",
+                "Missing: [4]",
+                "Unexpected: [3]"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_contain_exactly_err_order() {
+        set_override(false);
+        assert_eq!(
+            contain_exactly([1, 3, 2])(&vec![1, 2, 3]),
+            expected_err(vec![
+                "Expected [1, 2, 3] to contain exactly [1, 3, 2]",
+                "Elements are in a different order, first difference at index 1"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_contain_exactly_err_length() {
+        set_override(false);
+        assert_eq!(
+            contain_exactly([1, 2])(&vec![1, 2, 3]),
+            expected_err(vec![
+                "Expected [1, 2, 3] to contain exactly [1, 2]",
+                "Unexpected: [3]"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_contain_in_any_order_ok() {
+        assert_eq!(contain_in_any_order([3, 1, 2])(&vec![1, 2, 3]), Ok(()));
+    }
+
+    #[test]
+    fn test_contain_in_any_order_err() {
+        set_override(false);
+        assert_eq!(
+            contain_in_any_order([2, 2, 1])(&vec![1, 2, 3]),
+            expected_err(vec![
+                "Expected [1, 2, 3] to contain [2, 2, 1] in any order",
+                "Missing: [2]",
+                "Unexpected: [3]"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_be_empty_ok() {
+        assert_eq!(be_empty(&Vec::<u8>::new()), Ok(()));
+    }
+
+    #[test]
+    fn test_be_empty_err() {
+        set_override(false);
+        assert_eq!(
+            be_empty(&vec![1]),
+            expected_err(vec!["Expected [1] to be empty"])
+        );
+    }
+
+    #[test]
+    fn test_have_len_ok() {
+        assert_eq!(have_len(2)(&BTreeMap::from([(1, 2), (3, 4)])), Ok(()));
+    }
+
+    #[test]
+    fn test_have_len_err() {
+        set_override(false);
+        assert_eq!(
+            have_len(2)(&vec![1, 2, 3]),
+            expected_err(vec!["Expected [1, 2, 3] to have length 2, but it has 3"])
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::fmt::Debug;
+
+use colored::Colorize;
+use lets_expect_core::{
+    assertions::{assertion_error::AssertionError, assertion_result::AssertionResult},
+    utils::indent::indent,
+};
+
+/// Renders a failed inner assertion as a branch of the combinator's message tree.
+fn branch(error: &AssertionError) -> Vec<String> {
+    let mut lines = error.message.iter();
+    let mut branch = lines
+        .next()
+        .map(|first| vec![format!("{} {}", "✗".red().bold(), first)])
+        .unwrap_or_default();
+    branch.extend(lines.map(|line| format!("  {}", line)));
+
+    indent(&branch, 1)
+}
+
+fn tree<'a>(header: &str, errors: impl Iterator<Item = &'a AssertionError>) -> AssertionError {
+    let mut message = vec![header.to_string()];
+    message.extend(errors.flat_map(branch));
+    AssertionError::new(message)
+}
+
+pub fn not<R>(assertion: impl Fn(&R) -> AssertionResult) -> impl Fn(&R) -> AssertionResult
+where
+    R: Debug + ?Sized,
+{
+    move |received| match assertion(received) {
+        Ok(()) => Err(AssertionError::new(vec![format!(
+            "Expected {} to fail the negated assertion, but it passed",
+            format!("{:?}", received).red().bold()
+        )])),
+        Err(_) => Ok(()),
+    }
+}
+
+pub fn both<R>(
+    first: impl Fn(&R) -> AssertionResult,
+    second: impl Fn(&R) -> AssertionResult,
+) -> impl Fn(&R) -> AssertionResult {
+    move |received| {
+        let results = [first(received), second(received)];
+
+        if results.iter().all(Result::is_ok) {
+            Ok(())
+        } else {
+            Err(tree(
+                "Expected both assertions to pass",
+                results.iter().filter_map(|result| result.as_ref().err()),
+            ))
+        }
+    }
+}
+
+pub fn either<R>(
+    first: impl Fn(&R) -> AssertionResult,
+    second: impl Fn(&R) -> AssertionResult,
+) -> impl Fn(&R) -> AssertionResult {
+    move |received| {
+        let results = [first(received), second(received)];
+
+        if results.iter().any(Result::is_ok) {
+            Ok(())
+        } else {
+            Err(tree(
+                "Expected either assertion to pass",
+                results.iter().filter_map(|result| result.as_ref().err()),
+            ))
+        }
+    }
+}
+
+pub fn one_of<R, A, I>(assertions: I) -> impl Fn(&R) -> AssertionResult
+where
+    A: Fn(&R) -> AssertionResult,
+    I: IntoIterator<Item = A>,
+{
+    let assertions = assertions.into_iter().collect::<Vec<A>>();
+
+    move |received| {
+        let results = assertions
+            .iter()
+            .map(|assertion| assertion(received))
+            .collect::<Vec<AssertionResult>>();
+
+        if results.iter().any(Result::is_ok) {
+            Ok(())
+        } else {
+            Err(tree(
+                "Expected one of the assertions to pass",
+                results.iter().filter_map(|result| result.as_ref().err()),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{equality::equal, expected_err::expected_err, partial_ord::*};
+    use colored::control::set_override;
+
+    #[test]
+    fn test_not_ok() {
+        assert_eq!(not(equal(1))(&2), Ok(()));
+    }
+
+    #[test]
+    fn test_not_err() {
+        set_override(false);
+        assert_eq!(
+            not(equal(1))(&1),
+            expected_err(vec![
+                "Expected 1 to fail the negated assertion, but it passed"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_both_ok() {
+        assert_eq!(both(be_greater_than(1), be_less_than(3))(&2), Ok(()));
+    }
+
+    #[test]
+    fn test_both_err() {
+        set_override(false);
+        assert_eq!(
+            both(be_greater_than(1), be_less_than(3))(&5),
+            expected_err(vec![
+                "Expected both assertions to pass",
+                "    ✗ Expected 5 to be less than 3"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_either_ok() {
+        assert_eq!(either(be_less_than(1), be_greater_than(3))(&5), Ok(()));
+    }
+
+    #[test]
+    fn test_either_err_nested() {
+        set_override(false);
+        assert_eq!(
+            either(equal(1), both(be_greater_than(3), be_less_than(5)))(&2),
+            expected_err(vec![
+                "Expected either assertion to pass",
+                "    ✗ Expected: 1",
+                "      Received: 2",
+                "    ✗ Expected both assertions to pass",
+                "          ✗ Expected 2 to be greater than 3"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_one_of_ok() {
+        assert_eq!(one_of([equal(1), equal(2), equal(3)])(&2), Ok(()));
+    }
+
+    #[test]
+    fn test_one_of_err() {
+        set_override(false);
+        assert_eq!(
+            one_of([equal(1), equal(2)])(&3),
+            expected_err(vec![
+                "Expected one of the assertions to pass",
+                "    ✗ Expected: 1",
+                "      Received: 3",
+                "    ✗ Expected: 2",
+                "      Received: 3"
+            ])
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use colored::Colorize;
+
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Unchanged(&'a str),
+    Expected(&'a str),
+    Received(&'a str),
+}
+
+/// Renders a line-based diff of two multi-line strings.
+/// Unchanged regions longer than a few lines are folded.
+pub fn diff_lines(expected: &str, received: &str) -> Vec<String> {
+    let expected = expected.lines().collect::<Vec<&str>>();
+    let received = received.lines().collect::<Vec<&str>>();
+    let diff = diff(&expected, &received);
+
+    let mut lines = vec![format!(
+        "Diff ({}, {}):",
+        "- expected".green().bold(),
+        "+ received".red().bold()
+    )];
+    lines.extend(fold(&diff).into_iter().map(|line| match line {
+        Some(DiffLine::Unchanged(line)) => format!("  {}", line),
+        Some(DiffLine::Expected(line)) => format!("- {}", line).green().to_string(),
+        Some(DiffLine::Received(line)) => format!("+ {}", line).red().to_string(),
+        None => "  ...".dimmed().to_string(),
+    }));
+
+    lines
+}
+
+fn diff<'a>(expected: &[&'a str], received: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let mut lcs = vec![vec![0usize; received.len() + 1]; expected.len() + 1];
+
+    for i in (0..expected.len()).rev() {
+        for j in (0..received.len()).rev() {
+            lcs[i][j] = if expected[i] == received[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < expected.len() && j < received.len() {
+        if expected[i] == received[j] {
+            result.push(DiffLine::Unchanged(expected[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            result.push(DiffLine::Expected(expected[i]));
+            i += 1;
+        } else {
+            result.push(DiffLine::Received(received[j]));
+            j += 1;
+        }
+    }
+
+    result.extend(expected[i..].iter().map(|line| DiffLine::Expected(line)));
+    result.extend(received[j..].iter().map(|line| DiffLine::Received(line)));
+
+    result
+}
+
+/// Keeps changed lines and their context. `None` marks a folded region.
+fn fold<'a, 'b>(diff: &'b [DiffLine<'a>]) -> Vec<Option<&'b DiffLine<'a>>> {
+    let changed = diff
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Unchanged(_)))
+        .map(|(index, _)| index)
+        .collect::<Vec<usize>>();
+
+    let visible = |index: usize| {
+        changed
+            .iter()
+            .any(|changed| index + CONTEXT_LINES >= *changed && index <= changed + CONTEXT_LINES)
+    };
+
+    let mut result = Vec::new();
+    for (index, line) in diff.iter().enumerate() {
+        if visible(index) {
+            result.push(Some(line));
+        } else if result.last().map_or(true, Option::is_some) {
+            result.push(None);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use colored::control::set_override;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(
+            diff(&["a", "b", "c"], &["a", "x", "c", "d"]),
+            vec![
+                DiffLine::Unchanged("a"),
+                DiffLine::Expected("b"),
+                DiffLine::Received("x"),
+                DiffLine::Unchanged("c"),
+                DiffLine::Received("d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        set_override(false);
+        assert_eq!(
+            diff_lines("[\n    1,\n    2,\n]", "[\n    1,\n    3,\n]"),
+            vec![
+                "Diff (- expected, + received):",
+                "  [",
+                "      1,",
+                "-     2,",
+                "+     3,",
+                "  ]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_lines_folds_unchanged_regions() {
+        set_override(false);
+        let expected = (0..20).map(|i| i.to_string()).collect::<Vec<String>>();
+        let mut received = expected.clone();
+        received[10] = "ten".to_string();
+
+        assert_eq!(
+            diff_lines(&expected.join("\n"), &received.join("\n")),
+            vec![
+                "Diff (- expected, + received):",
+                "  ...",
+                "  7",
+                "  8",
+                "  9",
+                "- 10",
+                "+ ten",
+                "  11",
+                "  12",
+                "  13",
+                "  ...",
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::diff::diff_lines;
 use colored::Colorize;
 use lets_expect_core::assertions::{
     assertion_error::AssertionError, assertion_result::AssertionResult,
@@ -12,6 +13,15 @@ where
         if expected == *received {
             Ok(())
         } else {
+            let expected_pretty = format!("{:#?}", expected);
+            let received_pretty = format!("{:#?}", received);
+
+            if expected_pretty.contains('\n') || received_pretty.contains('\n') {
+                return Err(AssertionError {
+                    message: diff_lines(&expected_pretty, &received_pretty),
+                });
+            }
+
             let expected = format!("{:?}", expected).green().bold();
             let received = format!("{:?}", received).red().bold();
             Err(AssertionError {
@@ -60,6 +70,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_equal_err_multiline() {
+        set_override(false);
+        assert_eq!(
+            equal(vec![1, 2])(&vec![1, 3]),
+            expected_err(vec![
+                "Diff (- expected, + received):",
+                "  [",
+                "      1,",
+                "-     2,",
+                "+     3,",
+                "  ]",
+            ])
+        );
+    }
+
     #[test]
     fn test_not_equal_ok() {
         assert_eq!(not_equal(1)(&2), Ok(()));
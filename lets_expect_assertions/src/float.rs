@@ -0,0 +1,286 @@
+use std::fmt::Debug;
+
+use colored::Colorize;
+use lets_expect_core::assertions::{
+    assertion_error::AssertionError, assertion_result::AssertionResult,
+};
+
+pub trait FloatingPoint: Copy + Debug + PartialEq {
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn is_infinite(self) -> bool;
+    /// `|self - other|` divided by the larger of the two magnitudes.
+    fn relative_difference(self, other: Self) -> Self;
+    fn within_relative_tolerance(self, other: Self, tolerance: Self) -> bool;
+    /// Number of representable values between `self` and `other`, `None` if either is NaN.
+    fn ulps_between(self, other: Self) -> Option<u64>;
+}
+
+macro_rules! impl_floating_point {
+    ($float:ty, $int:ty) => {
+        impl FloatingPoint for $float {
+            fn is_nan(self) -> bool {
+                <$float>::is_nan(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$float>::is_finite(self)
+            }
+
+            fn is_infinite(self) -> bool {
+                <$float>::is_infinite(self)
+            }
+
+            fn relative_difference(self, other: Self) -> Self {
+                if self == other {
+                    0.0
+                } else if <$float>::is_infinite(self) || <$float>::is_infinite(other) {
+                    // Dividing by an infinite magnitude would give NaN.
+                    <$float>::INFINITY
+                } else {
+                    (self - other).abs() / self.abs().max(other.abs())
+                }
+            }
+
+            fn within_relative_tolerance(self, other: Self, tolerance: Self) -> bool {
+                if <$float>::is_infinite(self) || <$float>::is_infinite(other) {
+                    return self == other;
+                }
+
+                self == other || (self - other).abs() <= tolerance * self.abs().max(other.abs())
+            }
+
+            fn ulps_between(self, other: Self) -> Option<u64> {
+                if <$float>::is_nan(self) || <$float>::is_nan(other) {
+                    return None;
+                }
+
+                // Maps the bit patterns onto a monotonic integer line so that
+                // adjacent floats differ by one. -0.0 and 0.0 both map to 0.
+                let ordered = |value: Self| {
+                    let bits = value.to_bits() as $int;
+                    if bits < 0 {
+                        i64::from(<$int>::MIN) - i64::from(bits)
+                    } else {
+                        i64::from(bits)
+                    }
+                };
+
+                Some((i128::from(ordered(self)) - i128::from(ordered(other))).unsigned_abs() as u64)
+            }
+        }
+    };
+}
+
+impl_floating_point!(f32, i32);
+impl_floating_point!(f64, i64);
+
+pub fn be_approximately<R>(expected: R, relative_tolerance: R) -> impl Fn(&R) -> AssertionResult
+where
+    R: FloatingPoint,
+{
+    move |received| {
+        if received.within_relative_tolerance(expected, relative_tolerance) {
+            Ok(())
+        } else {
+            let difference = format!("{:?}", received.relative_difference(expected))
+                .red()
+                .bold();
+            let expected = format!("{:?}", expected).green().bold();
+            let relative_tolerance = format!("{:?}", relative_tolerance).green().bold();
+            let received = format!("{:?}", received).red().bold();
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected {} to be approximately {} with a relative tolerance of {}, but the relative difference is {}",
+                    received, expected, relative_tolerance, difference
+                )],
+            })
+        }
+    }
+}
+
+pub fn be_within_ulps<R>(expected: R, ulps: u64) -> impl Fn(&R) -> AssertionResult
+where
+    R: FloatingPoint,
+{
+    move |received| {
+        let distance = received.ulps_between(expected);
+
+        if distance.is_some_and(|distance| distance <= ulps) {
+            Ok(())
+        } else {
+            let distance = match distance {
+                Some(distance) => format!("it is {} ULPs away", distance.to_string().red().bold()),
+                None => "NaN is not within any distance".to_string(),
+            };
+            let expected = format!("{:?}", expected).green().bold();
+            let ulps = ulps.to_string().green().bold();
+            let received = format!("{:?}", received).red().bold();
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected {} to be within {} ULPs of {}, but {}",
+                    received, ulps, expected, distance
+                )],
+            })
+        }
+    }
+}
+
+pub fn be_nan<R>(received: &R) -> AssertionResult
+where
+    R: FloatingPoint,
+{
+    if received.is_nan() {
+        Ok(())
+    } else {
+        let received = format!("{:?}", received).red().bold();
+        Err(AssertionError {
+            message: vec![format!("Expected {} to be NaN", received)],
+        })
+    }
+}
+
+pub fn be_finite<R>(received: &R) -> AssertionResult
+where
+    R: FloatingPoint,
+{
+    if received.is_finite() {
+        Ok(())
+    } else {
+        let received = format!("{:?}", received).red().bold();
+        Err(AssertionError {
+            message: vec![format!("Expected {} to be finite", received)],
+        })
+    }
+}
+
+pub fn be_infinite<R>(received: &R) -> AssertionResult
+where
+    R: FloatingPoint,
+{
+    if received.is_infinite() {
+        Ok(())
+    } else {
+        let received = format!("{:?}", received).red().bold();
+        Err(AssertionError {
+            message: vec![format!("Expected {} to be infinite", received)],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expected_err::expected_err;
+    use colored::control::set_override;
+
+    #[test]
+    fn test_be_approximately_ok() {
+        assert_eq!(be_approximately(1_000_000.0, 1e-6)(&1_000_000.5), Ok(()));
+        assert_eq!(be_approximately(1e-9_f32, 1e-3)(&1.0001e-9), Ok(()));
+        assert_eq!(
+            be_approximately(f64::INFINITY, 1e-3)(&f64::INFINITY),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_be_approximately_err() {
+        set_override(false);
+        assert_eq!(
+            be_approximately(2.0, 0.1)(&2.5),
+            expected_err(vec![
+                "Expected 2.5 to be approximately 2.0 with a relative tolerance of 0.1, but the relative difference is 0.2"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_be_approximately_err_nan() {
+        assert!(be_approximately(f64::NAN, 0.1)(&f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_be_approximately_err_infinite() {
+        set_override(false);
+        assert_eq!(
+            be_approximately(1.0, 0.1)(&f64::INFINITY),
+            expected_err(vec![
+                "Expected inf to be approximately 1.0 with a relative tolerance of 0.1, but the relative difference is inf"
+            ])
+        );
+        assert!(be_approximately(f64::INFINITY, 0.1)(&f64::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_relative_difference_infinite() {
+        assert_eq!(f64::INFINITY.relative_difference(1.0), f64::INFINITY);
+        assert_eq!(
+            1.0_f32.relative_difference(f32::NEG_INFINITY),
+            f32::INFINITY
+        );
+        assert_eq!(f64::INFINITY.relative_difference(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_ulps_between_zeros() {
+        assert_eq!(0.0_f64.ulps_between(-0.0), Some(0));
+        assert_eq!((-0.0_f32).ulps_between(f32::from_bits(1)), Some(1));
+        assert_eq!(f32::from_bits(1).ulps_between(-f32::from_bits(1)), Some(2));
+    }
+
+    #[test]
+    fn test_be_within_ulps_ok() {
+        assert_eq!(be_within_ulps(0.3, 1)(&(0.1 + 0.2)), Ok(()));
+        assert_eq!(be_within_ulps(0.0_f32, 2)(&-0.0), Ok(()));
+    }
+
+    #[test]
+    fn test_be_within_ulps_err() {
+        set_override(false);
+        assert_eq!(
+            be_within_ulps(1.0_f32, 1)(&1.0000002),
+            expected_err(vec![
+                "Expected 1.0000002 to be within 1 ULPs of 1.0, but it is 2 ULPs away"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_be_within_ulps_err_nan() {
+        set_override(false);
+        assert_eq!(
+            be_within_ulps(1.0, 4)(&f64::NAN),
+            expected_err(vec![
+                "Expected NaN to be within 4 ULPs of 1.0, but NaN is not within any distance"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_be_nan() {
+        set_override(false);
+        assert_eq!(be_nan(&f64::NAN), Ok(()));
+        assert_eq!(be_nan(&1.0), expected_err(vec!["Expected 1.0 to be NaN"]));
+    }
+
+    #[test]
+    fn test_be_finite() {
+        set_override(false);
+        assert_eq!(be_finite(&1.0_f32), Ok(()));
+        assert_eq!(
+            be_finite(&f32::NEG_INFINITY),
+            expected_err(vec!["Expected -inf to be finite"])
+        );
+    }
+
+    #[test]
+    fn test_be_infinite() {
+        set_override(false);
+        assert_eq!(be_infinite(&f64::INFINITY), Ok(()));
+        assert_eq!(
+            be_infinite(&f64::NAN),
+            expected_err(vec!["Expected NaN to be infinite"])
+        );
+    }
+}
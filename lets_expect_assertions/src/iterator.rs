@@ -1,45 +1,229 @@
-use std::slice::Iter;
+use std::fmt::Debug;
 
-use lets_expect_core::assertions::{
-    assertion_error::AssertionError, assertion_result::AssertionResult,
+use colored::Colorize;
+use lets_expect_core::{
+    assertions::{assertion_error::AssertionError, assertion_result::AssertionResult},
+    utils::indent::indent,
 };
 
-pub fn all<R>(
+struct ElementResult<'a, R> {
+    index: usize,
+    element: &'a R,
+    result: AssertionResult,
+}
+
+impl<'a, R: Debug> ElementResult<'a, R> {
+    fn label(&self) -> String {
+        format!("[{}] {}", self.index, format!("{:?}", self.element).bold())
+    }
+
+    fn pretty_print(&self) -> Vec<String> {
+        match &self.result {
+            Ok(_) => vec![self.label()],
+            Err(error) if error.message.len() == 1 => {
+                vec![format!("{}: {}", self.label(), error.message[0])]
+            }
+            Err(error) => {
+                let mut lines = vec![format!("{}:", self.label())];
+                lines.extend(indent(&error.message, 1));
+                lines
+            }
+        }
+    }
+}
+
+fn evaluate<'a, R, I>(
+    iter: &mut I,
+    assertion: impl Fn(&R) -> AssertionResult,
+) -> Vec<ElementResult<'a, R>>
+where
+    R: 'a,
+    I: Iterator<Item = &'a R> + ?Sized,
+{
+    iter.enumerate()
+        .map(|(index, element)| ElementResult {
+            index,
+            element,
+            result: assertion(element),
+        })
+        .collect()
+}
+
+fn quantifier_error<R: Debug>(
+    header: String,
+    results: &[ElementResult<R>],
+    passed: bool,
+) -> AssertionResult {
+    let mut message = vec![header];
+    message.extend(
+        results
+            .iter()
+            .filter(|result| result.result.is_ok() == passed)
+            .flat_map(ElementResult::pretty_print),
+    );
+    Err(AssertionError::new(message))
+}
+
+fn count_passed<R>(results: &[ElementResult<R>]) -> usize {
+    results
+        .iter()
+        .filter(|result| result.result.is_ok())
+        .count()
+}
+
+pub fn all<'a, R, I>(
     assertion: impl Fn(&R) -> AssertionResult,
-) -> impl FnOnce(&mut Iter<R>) -> AssertionResult {
+) -> impl FnOnce(&mut I) -> AssertionResult
+where
+    R: Debug + 'a,
+    I: Iterator<Item = &'a R> + ?Sized,
+{
     move |iter| {
-        let results: Vec<AssertionResult> = iter.map(assertion).collect();
+        let results = evaluate(iter, assertion);
 
-        if results.iter().all(|result| result.is_ok()) {
+        if results.iter().all(|result| result.result.is_ok()) {
             Ok(())
         } else {
-            let mut errors = vec![];
-            for result in results {
-                if let Err(err) = result {
-                    errors.push(err.message.join(" "));
-                }
-            }
-            Err(AssertionError::new(errors))
+            Err(AssertionError::new(
+                results
+                    .iter()
+                    .filter(|result| result.result.is_err())
+                    .flat_map(ElementResult::pretty_print)
+                    .collect(),
+            ))
         }
     }
 }
 
-pub fn any<R>(
+pub fn any<'a, R, I>(
     assertion: impl Fn(&R) -> AssertionResult,
-) -> impl FnOnce(&mut dyn Iterator<Item = &R>) -> AssertionResult {
+) -> impl FnOnce(&mut I) -> AssertionResult
+where
+    R: Debug + 'a,
+    I: Iterator<Item = &'a R> + ?Sized,
+{
     move |iter| {
-        let results: Vec<AssertionResult> = iter.map(assertion).collect();
+        let results = evaluate(iter, assertion);
 
-        if results.iter().any(|result| result.is_ok()) {
+        if results.iter().any(|result| result.result.is_ok()) {
             Ok(())
         } else {
-            let mut errors = vec![];
-            for result in results {
-                if let Err(err) = result {
-                    errors.push(err.message.join(" "));
-                }
-            }
-            Err(AssertionError::new(errors))
+            Err(AssertionError::new(
+                results
+                    .iter()
+                    .flat_map(ElementResult::pretty_print)
+                    .collect(),
+            ))
+        }
+    }
+}
+
+pub fn none<'a, R, I>(
+    assertion: impl Fn(&R) -> AssertionResult,
+) -> impl FnOnce(&mut I) -> AssertionResult
+where
+    R: Debug + 'a,
+    I: Iterator<Item = &'a R> + ?Sized,
+{
+    move |iter| {
+        let results = evaluate(iter, assertion);
+        let passed = count_passed(&results);
+
+        if passed == 0 {
+            Ok(())
+        } else {
+            quantifier_error(
+                format!(
+                    "Expected no elements to pass, but {} did",
+                    passed.to_string().red().bold()
+                ),
+                &results,
+                true,
+            )
+        }
+    }
+}
+
+pub fn exactly<'a, R, I>(
+    expected: usize,
+    assertion: impl Fn(&R) -> AssertionResult,
+) -> impl FnOnce(&mut I) -> AssertionResult
+where
+    R: Debug + 'a,
+    I: Iterator<Item = &'a R> + ?Sized,
+{
+    move |iter| {
+        let results = evaluate(iter, assertion);
+        let passed = count_passed(&results);
+
+        if passed == expected {
+            Ok(())
+        } else {
+            quantifier_error(
+                format!(
+                    "Expected exactly {} elements to pass, but {} did",
+                    expected.to_string().green().bold(),
+                    passed.to_string().red().bold()
+                ),
+                &results,
+                passed > expected,
+            )
+        }
+    }
+}
+
+pub fn at_least<'a, R, I>(
+    expected: usize,
+    assertion: impl Fn(&R) -> AssertionResult,
+) -> impl FnOnce(&mut I) -> AssertionResult
+where
+    R: Debug + 'a,
+    I: Iterator<Item = &'a R> + ?Sized,
+{
+    move |iter| {
+        let results = evaluate(iter, assertion);
+        let passed = count_passed(&results);
+
+        if passed >= expected {
+            Ok(())
+        } else {
+            quantifier_error(
+                format!(
+                    "Expected at least {} elements to pass, but {} did",
+                    expected.to_string().green().bold(),
+                    passed.to_string().red().bold()
+                ),
+                &results,
+                false,
+            )
+        }
+    }
+}
+
+pub fn at_most<'a, R, I>(
+    expected: usize,
+    assertion: impl Fn(&R) -> AssertionResult,
+) -> impl FnOnce(&mut I) -> AssertionResult
+where
+    R: Debug + 'a,
+    I: Iterator<Item = &'a R> + ?Sized,
+{
+    move |iter| {
+        let results = evaluate(iter, assertion);
+        let passed = count_passed(&results);
+
+        if passed <= expected {
+            Ok(())
+        } else {
+            quantifier_error(
+                format!(
+                    "Expected at most {} elements to pass, but {} did",
+                    expected.to_string().green().bold(),
+                    passed.to_string().red().bold()
+                ),
+                &results,
+                true,
+            )
         }
     }
 }
@@ -47,6 +231,17 @@ pub fn any<R>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::expected_err::expected_err;
+    use colored::control::set_override;
+    use std::collections::HashMap;
+
+    fn be_one(x: &i32) -> AssertionResult {
+        if *x == 1 {
+            Ok(())
+        } else {
+            Err(AssertionError::new(vec!["err".to_string()]))
+        }
+    }
 
     #[test]
     fn test_all_ones() {
@@ -54,7 +249,7 @@ mod tests {
             Ok(())
         } else {
             Err(AssertionError::new(vec!["err".to_string()]))
-        })(&mut vec![1, 1, 1].iter())
+        })(&mut [1, 1, 1].iter())
         .is_ok());
     }
 
@@ -64,17 +259,38 @@ mod tests {
             Ok(())
         } else {
             Err(AssertionError::new(vec!["err".to_string()]))
-        })(&mut vec![1, 2, 1].iter())
+        })(&mut [1, 2, 1].iter())
         .is_err());
     }
 
+    #[test]
+    fn test_all_reports_indices() {
+        set_override(false);
+        assert_eq!(
+            all(be_one)(&mut [1, 2, 1, 3].iter()),
+            expected_err(vec!["[1] 2: err", "[3] 3: err"])
+        );
+    }
+
+    #[test]
+    fn test_any_over_dyn_iterator() {
+        let iter: &mut dyn Iterator<Item = &i32> = &mut [2, 1].iter();
+        assert_eq!(any(be_one)(iter), Ok(()));
+    }
+
+    #[test]
+    fn test_all_over_map_keys() {
+        let map = HashMap::from([(1, "one")]);
+        assert_eq!(all(be_one)(&mut map.keys()), Ok(()));
+    }
+
     #[test]
     fn test_any_success() {
         assert!(any(|&x| if x == 1 {
             Ok(())
         } else {
             Err(AssertionError::new(vec!["err".to_string()]))
-        })(&mut vec![1, 0, 0].iter())
+        })(&mut [1, 0, 0].iter())
         .is_ok());
     }
 
@@ -84,7 +300,74 @@ mod tests {
             Ok(())
         } else {
             Err(AssertionError::new(vec!["err".to_string()]))
-        })(&mut vec![0, 0, 0].iter())
-        .is_ok());
+        })(&mut [0, 0, 0].iter())
+        .is_err());
+    }
+
+    #[test]
+    fn test_none_ok() {
+        assert_eq!(none(be_one)(&mut [2, 3].iter()), Ok(()));
+    }
+
+    #[test]
+    fn test_none_err() {
+        set_override(false);
+        assert_eq!(
+            none(be_one)(&mut [2, 1].iter()),
+            expected_err(vec!["Expected no elements to pass, but 1 did", "[1] 1"])
+        );
+    }
+
+    #[test]
+    fn test_exactly_ok() {
+        assert_eq!(exactly(2, be_one)(&mut [1, 2, 1].iter()), Ok(()));
+    }
+
+    #[test]
+    fn test_exactly_err() {
+        set_override(false);
+        assert_eq!(
+            exactly(2, be_one)(&mut [1, 2, 3].iter()),
+            expected_err(vec![
+                "Expected exactly 2 elements to pass, but 1 did",
+                "[1] 2: err",
+                "[2] 3: err"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_at_least_ok() {
+        assert_eq!(at_least(1, be_one)(&mut [1, 2, 1].iter()), Ok(()));
+    }
+
+    #[test]
+    fn test_at_least_err() {
+        set_override(false);
+        assert_eq!(
+            at_least(1, be_one)(&mut [2].iter()),
+            expected_err(vec![
+                "Expected at least 1 elements to pass, but 0 did",
+                "[0] 2: err"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_at_most_ok() {
+        assert_eq!(at_most(1, be_one)(&mut [1, 2].iter()), Ok(()));
+    }
+
+    #[test]
+    fn test_at_most_err() {
+        set_override(false);
+        assert_eq!(
+            at_most(1, be_one)(&mut [1, 1].iter()),
+            expected_err(vec![
+                "Expected at most 1 elements to pass, but 2 did",
+                "[0] 1",
+                "[1] 1"
+            ])
+        );
     }
 }
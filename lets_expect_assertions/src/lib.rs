@@ -16,13 +16,26 @@ pub mod assertions;
 
 pub mod bool;
 pub mod change;
+pub mod collection;
+pub mod combinators;
+pub mod diff;
 pub mod equality;
+pub mod float;
 pub mod iterator;
 pub mod match_pattern;
 pub mod option;
 pub mod panic;
 pub mod partial_ord;
 pub mod result;
+pub mod snapshot;
+pub mod string;
 
 #[cfg(test)]
 mod expected_err;
+
+/// Used by the assertion macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use lets_expect_core::assertions::binding::{Binding, CopyBinding, RefBinding};
+    pub use lets_expect_macro::match_pattern_guard;
+}
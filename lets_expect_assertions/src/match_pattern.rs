@@ -1,11 +1,30 @@
 pub use colored::Colorize;
 pub use lets_expect_core::assertions::assertion_error::AssertionError;
 
+/// Matches on a reference to the subject, like `match_pattern!(...) and` does. The guard gets the
+/// bound values where they're `Copy`, like it would in a `match` on the value itself.
 #[macro_export]
 macro_rules! match_pattern {
+    ($($pattern:pat_param)|+ if $guard:expr) => {
+        |received: &_| {
+            match received {
+                $($pattern)|+ if $crate::__private::match_pattern_guard!($crate::__private; $($pattern)|+; $guard) => Ok(()),
+                #[allow(unused_variables)]
+                $($pattern)|+ => {
+                    let received = format!("{:?}", received).red().bold();
+                    let guard = stringify!($guard).green().bold();
+                    Err(AssertionError { message: vec![format!("Expected {} to match pattern, but the guard {} failed", received, guard)] })
+                },
+                _ => {
+                    let received = format!("{:?}", received).red().bold();
+                    Err(AssertionError { message: vec![format!("Expected {} to match pattern", received)] })
+                },
+            }
+        }
+    };
     ($($pattern:pat_param)|+) => {
         |received: &_| {
-            match *received {
+            match received {
                 $($pattern)|+ => Ok(()),
                 _ => {
                     let received = format!("{:?}", received).red().bold();
@@ -18,9 +37,20 @@ macro_rules! match_pattern {
 
 #[macro_export]
 macro_rules! not_match_pattern {
+    ($($pattern:pat_param)|+ if $guard:expr) => {
+        |received: &_| {
+            match received {
+                $($pattern)|+ if $crate::__private::match_pattern_guard!($crate::__private; $($pattern)|+; $guard) => {
+                    let received = format!("{:?}", received).red().bold();
+                    Err(AssertionError { message: vec![format!("Expected {} to not match pattern", received)] })
+                },
+                _ => Ok(()),
+            }
+        }
+    };
     ($($pattern:pat_param)|+) => {
         |received: &_| {
-            match *received {
+            match received {
                 $($pattern)|+ => {
                     let received = format!("{:?}", received).red().bold();
                     Err(AssertionError { message: vec![format!("Expected {} to not match pattern", received)] })
@@ -67,4 +97,30 @@ mod tests {
             expected_err(vec!["Expected 1 to not match pattern"])
         );
     }
+
+    #[test]
+    fn test_match_pattern_guard_ok() {
+        assert_eq!(match_pattern!(Some(x) if x > 1)(&Some(2)), Ok(()));
+    }
+
+    #[test]
+    fn test_match_pattern_guard_err() {
+        set_override(false);
+        assert_eq!(
+            match_pattern!(Some(x) if x > 1)(&Some(1)),
+            expected_err(vec![
+                "Expected Some(1) to match pattern, but the guard x > 1 failed"
+            ])
+        );
+        assert_eq!(
+            match_pattern!(Some(x) if x > 1)(&None::<i32>),
+            expected_err(vec!["Expected None to match pattern"])
+        );
+    }
+
+    #[test]
+    fn test_not_match_pattern_guard() {
+        assert_eq!(not_match_pattern!(Some(x) if x > 1)(&Some(1)), Ok(()));
+        assert!(not_match_pattern!(Some(x) if x > 1)(&Some(2)).is_err());
+    }
 }
@@ -1,7 +1,28 @@
-use lets_expect_core::assertions::{
-    assertion_error::AssertionError, assertion_result::AssertionResult,
+use colored::Colorize;
+use lets_expect_core::{
+    assertions::{assertion_error::AssertionError, assertion_result::AssertionResult},
+    execution::expected_panic::expected_panic_location,
 };
-use std::any::Any;
+use std::any::{type_name, Any};
+
+fn panic_message(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+}
+
+fn describe_payload(payload: &(dyn Any + Send)) -> String {
+    let description = match panic_message(payload) {
+        Some(message) => format!("{:?}", message).red().bold().to_string(),
+        None => "a non-string payload".red().bold().to_string(),
+    };
+
+    match expected_panic_location() {
+        Some(location) => format!("{} at {}", description, location),
+        None => description,
+    }
+}
 
 pub fn panic<R>(result: &Result<R, Box<dyn Any + Send>>) -> AssertionResult {
     match result {
@@ -21,11 +42,69 @@ pub fn not_panic<R>(result: &Result<R, Box<dyn Any + Send>>) -> AssertionResult
     }
 }
 
+pub fn panic_with<R, E>(expected: E) -> impl Fn(&Result<R, Box<dyn Any + Send>>) -> AssertionResult
+where
+    E: AsRef<str>,
+{
+    move |result| {
+        let expected = expected.as_ref();
+
+        match result {
+            Err(payload)
+                if panic_message(payload.as_ref())
+                    .is_some_and(|message| message.contains(expected)) =>
+            {
+                Ok(())
+            }
+            Err(payload) => Err(AssertionError {
+                message: vec![format!(
+                    "Expected subject to panic with {}, but it panicked with {}",
+                    format!("{:?}", expected).green().bold(),
+                    describe_payload(payload.as_ref())
+                )],
+            }),
+            Ok(_) => Err(AssertionError {
+                message: vec![format!(
+                    "Expected subject to panic with {}, but it didn't",
+                    format!("{:?}", expected).green().bold()
+                )],
+            }),
+        }
+    }
+}
+
+pub fn panic_with_payload<T>(
+    assertion: impl Fn(&T) -> AssertionResult,
+) -> impl Fn(&Result<(), Box<dyn Any + Send>>) -> AssertionResult
+where
+    T: 'static,
+{
+    move |result| match result {
+        Err(payload) => match payload.downcast_ref::<T>() {
+            Some(payload) => assertion(payload),
+            None => Err(AssertionError {
+                message: vec![format!(
+                    "Expected subject to panic with a payload of type {}, but it panicked with {}",
+                    type_name::<T>().green().bold(),
+                    describe_payload(payload.as_ref())
+                )],
+            }),
+        },
+        Ok(_) => Err(AssertionError {
+            message: vec![format!(
+                "Expected subject to panic with a payload of type {}, but it didn't",
+                type_name::<T>().green().bold()
+            )],
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::expected_err::expected_err;
+    use crate::{equality::equal, expected_err::expected_err};
     use colored::control::set_override;
+    use lets_expect_core::execution::expected_panic::catch_expected_panic;
 
     #[test]
     fn test_panic_ok() {
@@ -58,4 +137,74 @@ mod tests {
             expected_err(vec!["Expected subject to not panic, but it did"])
         );
     }
+
+    #[test]
+    fn test_panic_with_ok() {
+        let result: Result<(), Box<dyn Any + Send>> = Err(Box::new("index out of bounds"));
+        assert_eq!(panic_with("out of bounds")(&result), Ok(()));
+
+        let result: Result<(), Box<dyn Any + Send>> = Err(Box::new("boom".to_string()));
+        assert_eq!(panic_with("boom")(&result), Ok(()));
+    }
+
+    #[test]
+    fn test_panic_with_err() {
+        let result: Result<(), Box<dyn Any + Send>> =
+            Err(Box::new("called `Option::unwrap()` on a `None` value"));
+        set_override(false);
+        assert_eq!(
+            panic_with("out of bounds")(&result),
+            expected_err(vec![
+                "Expected subject to panic with \"out of bounds\", but it panicked with \"called `Option::unwrap()` on a `None` value\""
+            ])
+        );
+    }
+
+    #[test]
+    fn test_panic_with_err_no_panic() {
+        let result: Result<(), Box<dyn Any + Send>> = Ok(());
+        set_override(false);
+        assert_eq!(
+            panic_with("boom")(&result),
+            expected_err(vec![
+                "Expected subject to panic with \"boom\", but it didn't"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_panic_with_payload_ok() {
+        let result: Result<(), Box<dyn Any + Send>> = Err(Box::new(42u32));
+        assert_eq!(panic_with_payload::<u32>(equal(42))(&result), Ok(()));
+    }
+
+    #[test]
+    fn test_panic_with_payload_err_type() {
+        let result: Result<(), Box<dyn Any + Send>> = Err(Box::new("boom"));
+        set_override(false);
+        assert_eq!(
+            panic_with_payload::<u32>(equal(42))(&result),
+            expected_err(vec![
+                "Expected subject to panic with a payload of type u32, but it panicked with \"boom\""
+            ])
+        );
+    }
+
+    #[test]
+    fn test_panic_with_payload_err_assertion() {
+        let result: Result<(), Box<dyn Any + Send>> = Err(Box::new(41u32));
+        set_override(false);
+        assert_eq!(
+            panic_with_payload::<u32>(equal(42))(&result),
+            expected_err(vec!["Expected: 42", "Received: 41"])
+        );
+    }
+
+    #[test]
+    fn test_panic_with_err_reports_location() {
+        let result = catch_expected_panic(|| panic!("boom"));
+        set_override(false);
+        let error = panic_with("bang")(&result).unwrap_err();
+        assert!(error.message[0].contains(&format!("\"boom\" at {}:", file!())));
+    }
 }
@@ -0,0 +1,187 @@
+use std::{env, fmt::Debug, fs, path::Path};
+
+use colored::Colorize;
+use lets_expect_core::{
+    assertions::{assertion_error::AssertionError, assertion_result::AssertionResult},
+    execution::current_test::CurrentTest,
+};
+
+use crate::diff::diff_lines;
+
+const UPDATE_VARIABLE: &str = "LETS_EXPECT_UPDATE";
+
+pub fn match_snapshot<R>(received: &R) -> AssertionResult
+where
+    R: Debug + ?Sized,
+{
+    let path =
+        CurrentTest::next_snapshot_path().map_err(|message| AssertionError::new(vec![message]))?;
+    let update = env::var(UPDATE_VARIABLE).is_ok_and(|value| value == "1");
+
+    check_snapshot(&path, &format!("{:#?}", received), update)
+}
+
+/// Compares `received` with the snapshot stored at `path`. Snapshots are only written when
+/// `update` is set, a missing one fails otherwise, so that a deleted or renamed snapshot doesn't
+/// pass unnoticed.
+fn check_snapshot(path: &Path, received: &str, update: bool) -> AssertionResult {
+    let stored = if update {
+        None
+    } else {
+        fs::read_to_string(path).ok()
+    };
+
+    match stored {
+        Some(stored) if stored.strip_suffix('\n').unwrap_or(&stored) == received => Ok(()),
+        Some(stored) => {
+            let mut message = vec![format!(
+                "Expected value to match snapshot {}",
+                path.display().to_string().green().bold()
+            )];
+            message.extend(diff_lines(
+                stored.strip_suffix('\n').unwrap_or(&stored),
+                received,
+            ));
+            message.push(format!(
+                "Run with {}=1 to update the snapshot",
+                UPDATE_VARIABLE
+            ));
+            Err(AssertionError::new(message))
+        }
+        None if update => write_snapshot(path, received),
+        None => Err(AssertionError::new(vec![
+            format!(
+                "Snapshot {} is missing",
+                path.display().to_string().red().bold()
+            ),
+            format!("Run with {}=1 to record it", UPDATE_VARIABLE),
+        ])),
+    }
+}
+
+fn write_snapshot(path: &Path, received: &str) -> AssertionResult {
+    let written = match path.parent() {
+        Some(directory) => fs::create_dir_all(directory),
+        None => Ok(()),
+    }
+    .and_then(|_| fs::write(path, format!("{}\n", received)));
+
+    written.map_err(|error| {
+        AssertionError::new(vec![format!(
+            "Failed to write snapshot {}: {}",
+            path.display(),
+            error
+        )])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use colored::control::set_override;
+    use std::path::PathBuf;
+
+    fn snapshot_path(name: &str) -> PathBuf {
+        let path = env::temp_dir()
+            .join(format!("lets_expect_snapshots_{}", std::process::id()))
+            .join(format!("{}.snap", name));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_check_snapshot_records_missing_snapshot_on_update() {
+        let path = snapshot_path("records_missing");
+        assert_eq!(check_snapshot(&path, "[\n    1,\n]", true), Ok(()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "[\n    1,\n]\n");
+    }
+
+    #[test]
+    fn test_check_snapshot_missing() {
+        let path = snapshot_path("missing");
+        set_override(false);
+        assert_eq!(
+            check_snapshot(&path, "1", false),
+            Err(AssertionError::new(vec![
+                format!("Snapshot {} is missing", path.display()),
+                "Run with LETS_EXPECT_UPDATE=1 to record it".to_string(),
+            ]))
+        );
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_check_snapshot_ok() {
+        let path = snapshot_path("ok");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "Point {\n    x: 1,\n}\n").unwrap();
+        assert_eq!(
+            check_snapshot(&path, "Point {\n    x: 1,\n}", false),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_check_snapshot_err() {
+        let path = snapshot_path("err");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "Point {\n    x: 1,\n}\n").unwrap();
+        set_override(false);
+        assert_eq!(
+            check_snapshot(&path, "Point {\n    x: 2,\n}", false),
+            Err(AssertionError::new(vec![
+                format!("Expected value to match snapshot {}", path.display()),
+                "Diff (- expected, + received):".to_string(),
+                "  Point {".to_string(),
+                "-     x: 1,".to_string(),
+                "+     x: 2,".to_string(),
+                "  }".to_string(),
+                "Run with LETS_EXPECT_UPDATE=1 to update the snapshot".to_string(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_check_snapshot_update() {
+        let path = snapshot_path("update");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "1\n").unwrap();
+        assert_eq!(check_snapshot(&path, "2", true), Ok(()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "2\n");
+    }
+
+    #[test]
+    fn test_match_snapshot_outside_of_test() {
+        assert!(match_snapshot(&1).is_err());
+    }
+
+    #[test]
+    fn test_match_snapshot_in_property_test() {
+        let _current_test = CurrentTest::enter_property("/crate", "crate::tests::when_a", "to_b");
+        set_override(false);
+        assert_eq!(
+            match_snapshot(&1),
+            Err(AssertionError::new(vec![
+                "match_snapshot can't be used in a property-based when, its values change every run"
+                    .to_string()
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_snapshot_path_follows_test_path() {
+        let _current_test = CurrentTest::enter("/crate", "crate::tests::expect_a", "to_b");
+        assert_eq!(
+            CurrentTest::next_snapshot_path(),
+            Ok(PathBuf::from(
+                "/crate/snapshots/crate__tests__expect_a__to_b.snap"
+            ))
+        );
+        assert_eq!(
+            CurrentTest::next_snapshot_path(),
+            Ok(PathBuf::from(
+                "/crate/snapshots/crate__tests__expect_a__to_b_2.snap"
+            ))
+        );
+    }
+}
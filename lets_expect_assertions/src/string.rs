@@ -0,0 +1,320 @@
+use crate::collection::Container;
+use colored::Colorize;
+use lets_expect_core::assertions::{
+    assertion_error::AssertionError, assertion_result::AssertionResult,
+};
+use regex::Regex;
+
+/// Formats a string like `Debug` would, marking the characters from `from` to `to` in red.
+fn highlight(value: &str, from: usize, to: usize) -> String {
+    let before = value.chars().take(from).collect::<String>();
+    let highlighted = value.chars().skip(from).take(to - from).collect::<String>();
+    let after = value.chars().skip(to).collect::<String>();
+
+    format!(
+        "\"{}{}{}\"",
+        before.escape_debug(),
+        highlighted.escape_debug().to_string().red().bold(),
+        after.escape_debug()
+    )
+}
+
+/// What a string can contain, a substring or a single character.
+pub trait StrPattern {
+    fn is_contained_in(&self, haystack: &str) -> bool;
+}
+
+impl StrPattern for str {
+    fn is_contained_in(&self, haystack: &str) -> bool {
+        haystack.contains(self)
+    }
+}
+
+impl StrPattern for String {
+    fn is_contained_in(&self, haystack: &str) -> bool {
+        haystack.contains(self.as_str())
+    }
+}
+
+impl StrPattern for char {
+    fn is_contained_in(&self, haystack: &str) -> bool {
+        haystack.contains(*self)
+    }
+}
+
+impl<T> StrPattern for &T
+where
+    T: StrPattern + ?Sized,
+{
+    fn is_contained_in(&self, haystack: &str) -> bool {
+        (**self).is_contained_in(haystack)
+    }
+}
+
+impl<E> Container<E> for str
+where
+    E: StrPattern,
+{
+    fn contains_element(&self, expected: &E) -> bool {
+        expected.is_contained_in(self)
+    }
+}
+
+impl<E> Container<E> for String
+where
+    E: StrPattern,
+{
+    fn contains_element(&self, expected: &E) -> bool {
+        expected.is_contained_in(self)
+    }
+}
+
+pub fn start_with<R, E>(expected: E) -> impl Fn(&R) -> AssertionResult
+where
+    R: AsRef<str> + ?Sized,
+    E: AsRef<str>,
+{
+    move |received| {
+        let received = received.as_ref();
+        let expected = expected.as_ref();
+
+        if received.starts_with(expected) {
+            Ok(())
+        } else {
+            let matching = received
+                .chars()
+                .zip(expected.chars())
+                .take_while(|(received, expected)| received == expected)
+                .count();
+            let mismatch_end = expected.chars().count().min(received.chars().count());
+            let mut message = vec![format!(
+                "Expected {} to start with {}",
+                highlight(received, matching, mismatch_end.max(matching)),
+                format!("{:?}", expected).green().bold()
+            )];
+            if matching < received.chars().count() {
+                message.push(format!("Strings differ at character {}", matching));
+            }
+            Err(AssertionError { message })
+        }
+    }
+}
+
+pub fn end_with<R, E>(expected: E) -> impl Fn(&R) -> AssertionResult
+where
+    R: AsRef<str> + ?Sized,
+    E: AsRef<str>,
+{
+    move |received| {
+        let received = received.as_ref();
+        let expected = expected.as_ref();
+
+        if received.ends_with(expected) {
+            Ok(())
+        } else {
+            let matching = received
+                .chars()
+                .rev()
+                .zip(expected.chars().rev())
+                .take_while(|(received, expected)| received == expected)
+                .count();
+            let received_length = received.chars().count();
+            let mismatch_start = received_length.saturating_sub(expected.chars().count());
+            let mut message = vec![format!(
+                "Expected {} to end with {}",
+                highlight(received, mismatch_start, received_length - matching),
+                format!("{:?}", expected).green().bold()
+            )];
+            if let Some(position) = received_length.checked_sub(matching + 1) {
+                message.push(format!("Strings differ at character {}", position));
+            }
+            Err(AssertionError { message })
+        }
+    }
+}
+
+pub fn have_char_count<R>(expected: usize) -> impl Fn(&R) -> AssertionResult
+where
+    R: AsRef<str> + ?Sized,
+{
+    move |received| {
+        let received = received.as_ref();
+        let count = received.chars().count();
+
+        if count == expected {
+            Ok(())
+        } else {
+            let received = format!("{:?}", received).red().bold();
+            let expected = expected.to_string().green().bold();
+            let count = count.to_string().red().bold();
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected {} to have {} characters, but it has {}",
+                    received, expected, count
+                )],
+            })
+        }
+    }
+}
+
+pub fn be_blank<R>(received: &R) -> AssertionResult
+where
+    R: AsRef<str> + ?Sized,
+{
+    let received = received.as_ref();
+
+    match received.chars().position(|char| !char.is_whitespace()) {
+        None => Ok(()),
+        Some(position) => {
+            let received = highlight(received, position, position + 1);
+            Err(AssertionError {
+                message: vec![format!(
+                    "Expected {} to be blank, but it has a non-whitespace character at {}",
+                    received, position
+                )],
+            })
+        }
+    }
+}
+
+pub fn match_regex<R>(pattern: &str) -> impl Fn(&R) -> AssertionResult
+where
+    R: AsRef<str> + ?Sized,
+{
+    let regex = Regex::new(pattern).map_err(|error| error.to_string());
+
+    move |received| {
+        let received = received.as_ref();
+
+        match &regex {
+            Ok(regex) if regex.is_match(received) => Ok(()),
+            Ok(regex) => {
+                let received = format!("{:?}", received).red().bold();
+                let pattern = format!("/{}/", regex.as_str()).green().bold();
+                Err(AssertionError {
+                    message: vec![format!("Expected {} to match {}", received, pattern)],
+                })
+            }
+            Err(error) => Err(AssertionError {
+                message: vec![format!("Invalid regular expression: {}", error)],
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{collection::contain, expected_err::expected_err};
+    use colored::control::set_override;
+
+    #[test]
+    fn test_contain_ok() {
+        assert_eq!(contain("ell")("hello"), Ok(()));
+        assert_eq!(contain('e')("hello"), Ok(()));
+        assert_eq!(contain('e')(&"hello".to_string()), Ok(()));
+    }
+
+    #[test]
+    fn test_contain_err() {
+        set_override(false);
+        assert_eq!(
+            contain("xyz")(&"hello".to_string()),
+            expected_err(vec!["Expected \"hello\" to contain \"xyz\""])
+        );
+    }
+
+    #[test]
+    fn test_start_with_ok() {
+        assert_eq!(start_with("he")("hello"), Ok(()));
+    }
+
+    #[test]
+    fn test_start_with_err() {
+        set_override(false);
+        assert_eq!(
+            start_with("help")("hello"),
+            expected_err(vec![
+                "Expected \"hello\" to start with \"help\"",
+                "Strings differ at character 3"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_end_with_ok() {
+        assert_eq!(end_with("llo")("hello"), Ok(()));
+    }
+
+    #[test]
+    fn test_end_with_err() {
+        set_override(false);
+        assert_eq!(
+            end_with("ilo")("hello"),
+            expected_err(vec![
+                "Expected \"hello\" to end with \"ilo\"",
+                "Strings differ at character 2"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_start_with_err_when_shorter() {
+        set_override(false);
+        assert_eq!(
+            start_with("hello")("he"),
+            expected_err(vec!["Expected \"he\" to start with \"hello\""])
+        );
+    }
+
+    #[test]
+    fn test_have_char_count_ok() {
+        assert_eq!(have_char_count(4)("zażó"), Ok(()));
+    }
+
+    #[test]
+    fn test_have_char_count_err() {
+        set_override(false);
+        assert_eq!(
+            have_char_count(3)("hello"),
+            expected_err(vec![
+                "Expected \"hello\" to have 3 characters, but it has 5"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_be_blank_ok() {
+        assert_eq!(be_blank(" \t\n"), Ok(()));
+    }
+
+    #[test]
+    fn test_be_blank_err() {
+        set_override(false);
+        assert_eq!(
+            be_blank("  a "),
+            expected_err(vec![
+                "Expected \"  a \" to be blank, but it has a non-whitespace character at 2"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_match_regex_ok() {
+        assert_eq!(match_regex("^h.*o$")("hello"), Ok(()));
+    }
+
+    #[test]
+    fn test_match_regex_err() {
+        set_override(false);
+        assert_eq!(
+            match_regex("^[0-9]+$")("hello"),
+            expected_err(vec!["Expected \"hello\" to match /^[0-9]+$/"])
+        );
+    }
+
+    #[test]
+    fn test_match_regex_invalid() {
+        assert!(match_regex("(")("hello").is_err());
+    }
+}
@@ -0,0 +1,27 @@
+/// A variable bound by a pattern matched on a reference, which makes it a reference too.
+///
+/// `(&Binding(variable)).value()` turns it back into a value if its type is `Copy` and leaves it a
+/// reference otherwise, so `match_pattern!(Some(id) if id > 0)` compares numbers while
+/// `match_pattern!(Some(name) if name.len() > 3)` doesn't need to move out of the subject.
+/// Both traits have to be in scope.
+pub struct Binding<'a, T: ?Sized>(pub &'a T);
+
+pub trait CopyBinding<T> {
+    fn value(&self) -> T;
+}
+
+impl<'a, T: Copy> CopyBinding<T> for Binding<'a, T> {
+    fn value(&self) -> T {
+        *self.0
+    }
+}
+
+pub trait RefBinding<'a, T: ?Sized> {
+    fn value(&self) -> &'a T;
+}
+
+impl<'a, T: ?Sized> RefBinding<'a, T> for &Binding<'a, T> {
+    fn value(&self) -> &'a T {
+        self.0
+    }
+}
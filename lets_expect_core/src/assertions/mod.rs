@@ -1,2 +1,3 @@
 pub mod assertion_error;
 pub mod assertion_result;
+pub mod binding;
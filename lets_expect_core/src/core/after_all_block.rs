@@ -0,0 +1,17 @@
+use syn::Block;
+
+use super::keyword;
+
+pub struct AfterAllBlock {
+    pub _keyword: keyword::after_all,
+    pub after_all: Block,
+}
+
+impl AfterAllBlock {
+    pub fn new(keyword: keyword::after_all, block: Block) -> Self {
+        Self {
+            _keyword: keyword,
+            after_all: block,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use syn::{spanned::Spanned, Error, ExprClosure};
+
+use super::keyword;
+
+pub struct AroundBlock {
+    pub _keyword: keyword::around,
+    pub around: ExprClosure,
+}
+
+impl AroundBlock {
+    pub fn new(keyword: keyword::around, around: ExprClosure) -> syn::Result<Self> {
+        if around.inputs.len() != 1 {
+            return Err(Error::new(
+                around.inputs.span(),
+                "Expected a closure with a single argument, the function running the test",
+            ));
+        }
+
+        Ok(Self {
+            _keyword: keyword,
+            around,
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use proc_macro2::Ident;
+use syn::{
+    parenthesized, parse::ParseStream, parse_quote, punctuated::Punctuated, spanned::Spanned,
+    token::Bracket, Attribute, Error, Token,
+};
+
+use super::keyword;
+
+/// Attributes that apply to a module as a whole. The others, like `#[ignore]`, are put on every
+/// test in the module.
+const MODULE_ATTRIBUTES: [&str; 7] = ["cfg", "cfg_attr", "allow", "warn", "deny", "forbid", "doc"];
+
+/// Outer attributes, `#[...]`, and tags, `#tag(slow, db)`. Tags are kept as `#[tag(...)]`
+/// attributes until they reach the runtime. Any other `#` is left alone, it may be a mode.
+pub fn parse_attributes(input: ParseStream) -> syn::Result<Vec<Attribute>> {
+    let mut attributes = Vec::new();
+
+    loop {
+        if input.peek(Token![#]) && input.peek2(Bracket) {
+            for attribute in input.call(Attribute::parse_outer)? {
+                if is_tag(&attribute) {
+                    tags(&attribute)?;
+                }
+                attributes.push(attribute);
+            }
+        } else if input.peek(Token![#]) && input.peek2(keyword::tag) {
+            input.parse::<Token![#]>()?;
+            input.parse::<keyword::tag>()?;
+            let content;
+            parenthesized!(content in input);
+            let tags = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            attributes.push(parse_quote! { #[tag(#tags)] });
+        } else {
+            return Ok(attributes);
+        }
+    }
+}
+
+pub fn module_attributes(attributes: &[Attribute]) -> Vec<Attribute> {
+    attributes
+        .iter()
+        .filter(|attribute| is_module_attribute(attribute))
+        .cloned()
+        .collect()
+}
+
+pub fn test_attributes(attributes: &[Attribute]) -> Vec<Attribute> {
+    attributes
+        .iter()
+        .filter(|attribute| !is_module_attribute(attribute))
+        .cloned()
+        .collect()
+}
+
+fn is_module_attribute(attribute: &Attribute) -> bool {
+    MODULE_ATTRIBUTES
+        .iter()
+        .any(|name| attribute.path.is_ident(name))
+}
+
+pub fn is_tag(attribute: &Attribute) -> bool {
+    attribute.path.is_ident("tag")
+}
+
+pub fn tags(attribute: &Attribute) -> syn::Result<Vec<Ident>> {
+    attribute
+        .parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)
+        .map(|tags| tags.into_iter().collect())
+        .map_err(|_| Error::new(attribute.span(), "Expected tags separated by commas"))
+}
+
+/// Attributes that only mean something to the test runner.
+pub fn is_test_only(attribute: &Attribute) -> bool {
+    attribute.path.is_ident("ignore") || is_should_panic(attribute)
+}
+
+pub fn is_should_panic(attribute: &Attribute) -> bool {
+    attribute.path.is_ident("should_panic")
+}
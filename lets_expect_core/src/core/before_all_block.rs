@@ -0,0 +1,28 @@
+use syn::{parse::ParseStream, Block, Ident, Token, Type};
+
+use super::keyword;
+
+pub struct BeforeAllBlock {
+    pub _keyword: keyword::before_all,
+    /// The variable the value of the block is bound to, e.g. `before_all index: Index { ... }`.
+    pub binding: Option<(Ident, Type)>,
+    pub before_all: Block,
+}
+
+impl BeforeAllBlock {
+    pub fn parse(keyword: keyword::before_all, input: ParseStream) -> syn::Result<Self> {
+        let binding = if input.peek(Ident) {
+            let identifier = input.parse::<Ident>()?;
+            input.parse::<Token![:]>()?;
+            Some((identifier, input.parse::<Type>()?))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            _keyword: keyword,
+            binding,
+            before_all: input.parse::<Block>()?,
+        })
+    }
+}
@@ -1,14 +1,33 @@
 use super::{
-    after_block::AfterBlock, before_block::BeforeBlock, create_test::create_test, expect::Expect,
-    expect_block::ExpectBlock, keyword, mode::Mode, runtime::Runtime, story::Story,
-    story_block::StoryBlock, to::To, to_block::ToBlock, when::When, when_block::WhenBlock,
+    after_all_block::AfterAllBlock,
+    after_block::AfterBlock,
+    around_block::AroundBlock,
+    attributes::{is_tag, parse_attributes},
+    before_all_block::BeforeAllBlock,
+    before_block::BeforeBlock,
+    create_test::create_test,
+    expect::Expect,
+    expect_block::ExpectBlock,
+    keyword,
+    mode::Mode,
+    module_hooks::Hooks,
+    runtime::Runtime,
+    shared::Shared,
+    skip_if::SkipIf,
+    story::Story,
+    story_block::StoryBlock,
+    to::To,
+    to_block::ToBlock,
+    when::When,
+    when_block::WhenBlock,
 };
 use proc_macro2::{Span, TokenStream};
 use quote::quote_spanned;
 use syn::{
     parse::{Parse, ParseBuffer, ParseStream},
+    parse_quote,
     spanned::Spanned,
-    Block, Error, Ident, Local, Stmt, Token,
+    Attribute, Block, Error, ExprClosure, Ident, Local, Stmt, Token,
 };
 
 #[derive(Default)]
@@ -19,10 +38,20 @@ pub struct Context {
     befores: Vec<BeforeBlock>,
     afters: Vec<AfterBlock>,
 
+    before_alls: Vec<BeforeAllBlock>,
+    after_alls: Vec<AfterAllBlock>,
+
+    arounds: Vec<AroundBlock>,
+
     expects: Vec<ExpectBlock>,
     whens: Vec<WhenBlock>,
     stories: Vec<StoryBlock>,
 
+    shared: Vec<Shared>,
+    behaves_like: Vec<Ident>,
+
+    skips: Vec<SkipIf>,
+
     mode: Option<Mode>,
 }
 
@@ -30,7 +59,7 @@ impl Parse for Context {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut context = Self::default();
 
-        if input.peek(Token![#]) {
+        if input.peek(Token![#]) && input.peek2(Ident) && !input.peek2(keyword::tag) {
             input.parse::<Token![#]>()?;
             let mode_ident = input.parse::<Ident>()?;
 
@@ -53,34 +82,83 @@ impl Parse for Context {
 }
 
 fn parse_single_context_item(input: &ParseBuffer, context: &mut Context) -> Result<(), Error> {
+    let attributes = parse_attributes(input)?;
     let next = input.lookahead1();
 
     if next.peek(Token![let]) {
-        handle_let(&mut context.lets, input)?;
+        handle_let(&mut context.lets, attributes, input)?;
     } else if next.peek(keyword::before) {
         let keyword = input.parse::<keyword::before>()?;
+        no_attributes(&attributes, "before")?;
         let before = handle_before(keyword, input)?;
         context.befores.push(before);
+    } else if next.peek(keyword::before_all) {
+        let keyword = input.parse::<keyword::before_all>()?;
+        no_attributes(&attributes, "before_all")?;
+        context
+            .before_alls
+            .push(BeforeAllBlock::parse(keyword, input)?);
+    } else if next.peek(keyword::after_all) {
+        let keyword = input.parse::<keyword::after_all>()?;
+        no_attributes(&attributes, "after_all")?;
+        let block = input.parse::<Block>()?;
+        context.after_alls.push(AfterAllBlock::new(keyword, block));
+    } else if next.peek(keyword::around) {
+        let keyword = input.parse::<keyword::around>()?;
+        no_attributes(&attributes, "around")?;
+        let around = input.parse::<ExprClosure>()?;
+        context.arounds.push(AroundBlock::new(keyword, around)?);
     } else if next.peek(keyword::after) {
         let keyword = input.parse::<keyword::after>()?;
+        no_attributes(&attributes, "after")?;
         let after = handle_after(keyword, input)?;
         context.afters.push(after);
     } else if next.peek(keyword::to) {
         let keyword = input.parse::<keyword::to>()?;
-        let to = handle_to(keyword, input)?;
+        let to = handle_to(keyword, attributes, input)?;
+        context.tos.push(to);
+    } else if next.peek(keyword::pending) {
+        input.parse::<keyword::pending>()?;
+        let keyword = input.parse::<keyword::to>()?;
+        let mut to = handle_to(keyword, attributes, input)?;
+        to.pending = true;
+        context.tos.push(to);
+    } else if next.peek(keyword::xto) {
+        let xto = input.parse::<keyword::xto>()?;
+        let to = handle_to(keyword::to(xto.span), ignored(attributes), input)?;
         context.tos.push(to);
+    } else if next.peek(keyword::xwhen) {
+        let xwhen = input.parse::<keyword::xwhen>()?;
+        let when = handle_when(keyword::when(xwhen.span), ignored(attributes), input)?;
+        context.whens.push(when);
+    } else if next.peek(keyword::xexpect) {
+        let xexpect = input.parse::<keyword::xexpect>()?;
+        let expect = handle_expect(keyword::expect(xexpect.span), ignored(attributes), input)?;
+        context.expects.push(expect);
     } else if next.peek(keyword::when) {
         let keyword = input.parse::<keyword::when>()?;
-        let when = handle_when(keyword, input)?;
+        let when = handle_when(keyword, attributes, input)?;
         context.whens.push(when);
     } else if next.peek(keyword::expect) {
         let keyword = input.parse::<keyword::expect>()?;
-        let expect = handle_expect(keyword, input)?;
+        let expect = handle_expect(keyword, attributes, input)?;
         context.expects.push(expect);
     } else if next.peek(keyword::story) {
         let keyword = input.parse::<keyword::story>()?;
-        let story = handle_story(keyword, input)?;
+        let story = handle_story(keyword, attributes, input)?;
         context.stories.push(story);
+    } else if next.peek(keyword::skip_if) {
+        input.parse::<keyword::skip_if>()?;
+        no_attributes(&attributes, "skip_if")?;
+        context.skips.push(input.parse::<SkipIf>()?);
+    } else if next.peek(keyword::shared) {
+        input.parse::<keyword::shared>()?;
+        no_attributes(&attributes, "shared")?;
+        context.shared.push(input.parse::<Shared>()?);
+    } else if next.peek(keyword::behaves_like) {
+        input.parse::<keyword::behaves_like>()?;
+        no_attributes(&attributes, "behaves_like")?;
+        context.behaves_like.push(input.parse::<Ident>()?);
     } else {
         return Err(next.error());
     }
@@ -88,6 +166,22 @@ fn parse_single_context_item(input: &ParseBuffer, context: &mut Context) -> Resu
     Ok(())
 }
 
+/// `xto`, `xwhen` and `xexpect` are skipped, their tests are ignored.
+fn ignored(mut attributes: Vec<Attribute>) -> Vec<Attribute> {
+    attributes.push(parse_quote! { #[ignore] });
+    attributes
+}
+
+fn no_attributes(attributes: &[Attribute], item: &str) -> syn::Result<()> {
+    match attributes.first() {
+        Some(attribute) => Err(Error::new(
+            attribute.span(),
+            format!("Attributes are not supported on `{}`", item),
+        )),
+        None => Ok(()),
+    }
+}
+
 fn handle_before(keyword: keyword::before, input: ParseStream) -> syn::Result<BeforeBlock> {
     let block = input.parse::<Block>()?;
     Ok(BeforeBlock::new(keyword, block))
@@ -98,26 +192,46 @@ fn handle_after(keyword: keyword::after, input: ParseStream) -> syn::Result<Afte
     Ok(AfterBlock::new(keyword, block))
 }
 
-fn handle_expect(keyword: keyword::expect, input: &ParseBuffer) -> syn::Result<ExpectBlock> {
+fn handle_expect(
+    keyword: keyword::expect,
+    attributes: Vec<Attribute>,
+    input: &ParseBuffer,
+) -> syn::Result<ExpectBlock> {
     let expect = input.parse::<Expect>()?;
-    Ok(ExpectBlock::new(keyword, expect))
+    Ok(ExpectBlock::new(keyword, expect, attributes))
 }
 
-fn handle_when(keyword: keyword::when, input: &ParseBuffer) -> syn::Result<WhenBlock> {
+fn handle_when(
+    keyword: keyword::when,
+    attributes: Vec<Attribute>,
+    input: &ParseBuffer,
+) -> syn::Result<WhenBlock> {
     let when = input.parse::<When>()?;
-    Ok(WhenBlock::new(keyword, when))
+    Ok(WhenBlock::new(keyword, when, attributes))
 }
 
-fn handle_to(keyword: keyword::to, input: &ParseBuffer) -> syn::Result<ToBlock> {
+fn handle_to(
+    keyword: keyword::to,
+    attributes: Vec<Attribute>,
+    input: &ParseBuffer,
+) -> syn::Result<ToBlock> {
     let to = input.parse::<To>()?;
-    Ok(ToBlock::new(keyword, to))
+    Ok(ToBlock::new(keyword, to, attributes))
 }
 
-fn handle_let(lets: &mut Vec<Local>, input: &ParseBuffer) -> syn::Result<()> {
+fn handle_let(
+    lets: &mut Vec<Local>,
+    attributes: Vec<Attribute>,
+    input: &ParseBuffer,
+) -> syn::Result<()> {
     let r#let = input.parse::<Stmt>()?;
 
     match r#let {
-        Stmt::Local(local) => {
+        Stmt::Local(mut local) => {
+            if let Some(tag) = attributes.iter().find(|attribute| is_tag(attribute)) {
+                return Err(Error::new(tag.span(), "Tags are not supported on `let`"));
+            }
+            local.attrs.splice(0..0, attributes);
             lets.push(local);
         }
         _ => return Err(Error::new(r#let.span(), "Expected a `let` statement")),
@@ -125,9 +239,13 @@ fn handle_let(lets: &mut Vec<Local>, input: &ParseBuffer) -> syn::Result<()> {
     Ok(())
 }
 
-fn handle_story(keyword: keyword::story, input: &ParseBuffer) -> Result<StoryBlock, Error> {
+fn handle_story(
+    keyword: keyword::story,
+    attributes: Vec<Attribute>,
+    input: &ParseBuffer,
+) -> Result<StoryBlock, Error> {
     let story = input.parse::<Story>()?;
-    Ok(StoryBlock::new(keyword, story))
+    Ok(StoryBlock::new(keyword, story, attributes))
 }
 
 impl Context {
@@ -140,46 +258,136 @@ impl Context {
     }
 
     pub fn to_tokens(&self, span: &Span, runtime: &Runtime) -> TokenStream {
-        let runtime = runtime.extend(
-            None,
-            &self.lets,
-            &self
-                .befores
-                .iter()
-                .map(|before| before.before.clone())
-                .collect::<Vec<Block>>(),
-            &self
-                .afters
-                .iter()
-                .map(|before| before.after.clone())
-                .collect::<Vec<Block>>(),
-            self.mode,
-        );
-
-        let tos = self.tos.iter().map(|to| {
-            let (to_tokens, dependencies) = to.to_tokens(&runtime);
-            let identifier = to.identifier();
-
-            let content = quote_spanned! { identifier.span() =>
-                let test_case = {
-                    #to_tokens
+        let runtime = runtime.add_shared(&self.shared);
+        let included = match self.included(&runtime, &mut Vec::new()) {
+            Ok(included) => included,
+            Err(error) => return error.to_compile_error(),
+        };
+        let contexts = || included.iter().chain(std::iter::once(self));
+
+        let before_alls = contexts()
+            .flat_map(|context| context.before_alls.iter())
+            .collect::<Vec<&BeforeAllBlock>>();
+        let after_alls = contexts()
+            .rev()
+            .flat_map(|context| context.after_alls.iter())
+            .collect::<Vec<&AfterAllBlock>>();
+        let hooks = if before_alls.is_empty() && after_alls.is_empty() {
+            Vec::new()
+        } else {
+            vec![Hooks::new(runtime.hooks.len(), &before_alls)]
+        };
+
+        // Included contexts behave as if they were the parents of this one. Their lets can be
+        // overridden and their befores run first.
+        let runtime = runtime
+            .extend(
+                None,
+                &hooks
+                    .iter()
+                    .flat_map(Hooks::lets)
+                    .chain(contexts().flat_map(|context| context.lets.iter().cloned()))
+                    .collect::<Vec<Local>>(),
+                &contexts()
+                    .flat_map(|context| context.befores.iter())
+                    .map(|before| before.before.clone())
+                    .collect::<Vec<Block>>(),
+                &contexts()
+                    .rev()
+                    .flat_map(|context| context.afters.iter())
+                    .map(|after| after.after.clone())
+                    .collect::<Vec<Block>>(),
+                self.mode,
+            )
+            .add_skips(
+                &contexts()
+                    .flat_map(|context| context.skips.iter().cloned())
+                    .collect::<Vec<SkipIf>>(),
+            )
+            .add_hooks(&hooks)
+            .add_arounds(
+                &contexts()
+                    .flat_map(|context| context.arounds.iter())
+                    .map(|around| around.around.clone())
+                    .collect::<Vec<ExprClosure>>(),
+            );
+
+        let tos = contexts()
+            .flat_map(|context| context.tos.iter())
+            .map(|to| {
+                let runtime = runtime.add_attributes(&to.attributes);
+                let runtime = if to.pending {
+                    runtime.pending()
+                } else {
+                    runtime
                 };
+                let (to_tokens, dependencies) = to.to_tokens(&runtime);
+                let identifier = to.identifier();
 
-                vec![test_case]
-            };
+                let content = quote_spanned! { identifier.span() =>
+                    let test_case = {
+                        #to_tokens
+                    };
 
-            create_test(&identifier, &runtime, &content, &dependencies)
-        });
+                    vec![test_case]
+                };
 
-        let stories = self.stories.iter().map(|story| story.to_tokens(&runtime));
-        let expects = self.expects.iter().map(|child| child.to_tokens(&runtime));
-        let whens = self.whens.iter().map(|child| child.to_tokens(&runtime));
+                create_test(&identifier, &runtime, &content, &dependencies)
+            })
+            .collect::<Vec<TokenStream>>();
+
+        let stories = contexts()
+            .flat_map(|context| context.stories.iter())
+            .map(|story| story.to_tokens(&runtime))
+            .collect::<Vec<TokenStream>>();
+        let expects = contexts()
+            .flat_map(|context| context.expects.iter())
+            .map(|child| child.to_tokens(&runtime))
+            .collect::<Vec<TokenStream>>();
+        let whens = contexts()
+            .flat_map(|context| context.whens.iter())
+            .map(|child| child.to_tokens(&runtime))
+            .collect::<Vec<TokenStream>>();
+        // Created last, when the number of tests in the module is known.
+        let parents = &runtime.hooks[..runtime.hooks.len() - hooks.len()];
+        let hooks = hooks
+            .iter()
+            .map(|hooks| hooks.to_tokens(span, parents, &before_alls, &after_alls));
 
         quote_spanned! { *span =>
+            #(#hooks)*
             #(#tos)*
             #(#stories)*
             #(#expects)*
             #(#whens)*
         }
     }
+
+    /// The shared contexts included with `behaves_like`, including the ones they include
+    /// themselves, outermost first.
+    fn included(&self, runtime: &Runtime, including: &mut Vec<Ident>) -> syn::Result<Vec<Self>> {
+        let mut included = Vec::new();
+
+        for name in &self.behaves_like {
+            if including.contains(name) {
+                return Err(Error::new(
+                    name.span(),
+                    format!("Shared context `{}` includes itself", name),
+                ));
+            }
+
+            let content = runtime.shared(name).ok_or_else(|| {
+                Error::new(name.span(), format!("Unknown shared context `{}`", name))
+            })?;
+            let context = syn::parse2::<Self>(content.clone())?;
+
+            including.push(name.clone());
+            included.extend(context.included(runtime, including)?);
+            including.pop();
+
+            included.push(context);
+        }
+
+        Ok(included)
+    }
 }
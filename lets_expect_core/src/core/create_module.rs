@@ -1,10 +1,15 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote_spanned;
+use syn::Attribute;
 
-pub fn create_module(span: &Span, identifier: &Ident, content: &TokenStream) -> TokenStream {
-    let content = content;
-
+pub fn create_module(
+    span: &Span,
+    identifier: &Ident,
+    attributes: &[Attribute],
+    content: &TokenStream,
+) -> TokenStream {
     quote_spanned! { *span =>
+        #(#attributes)*
         pub mod #identifier {
             #[allow(unused_imports)]
             pub use super::*;
@@ -1,14 +1,16 @@
 use std::collections::HashSet;
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote_spanned;
-use syn::Local;
+use quote::{quote_spanned, ToTokens};
+use syn::{Attribute, Block, Index, Local};
 
 use crate::utils::expr_dependencies::{block_dependencies, expr_dependencies};
 
 use super::{
+    attributes::{is_should_panic, is_test_only},
     ident_from_pat::ident_from_pat,
     mode::Mode,
+    module_hooks::Hooks,
     runtime::Runtime,
     topological_sort::{topological_sort, TopologicalSortError},
 };
@@ -37,11 +39,11 @@ pub fn create_test(
     let used: Vec<Local> = runtime
         .lets
         .iter()
-        .cloned()
         .filter(|l| {
             let ident = ident_from_pat(&l.pat).unwrap();
             used_lets.contains(&ident)
         })
+        .cloned()
         .collect();
 
     let lets = topological_sort(&used);
@@ -73,10 +75,130 @@ pub fn create_test(
         }
     };
 
-    let test_declaration = test_declaration(identifier, runtime.mode.unwrap_or(Mode::Test));
+    let mode = runtime.mode.unwrap_or(Mode::Test);
+    let test_declaration = test_declaration(identifier, mode, &runtime.attributes);
 
-    quote_spanned! { identifier.span() =>
-        #test_declaration {
+    // Ignored tests count too, they run with `--include-ignored`.
+    for hooks in &runtime.hooks {
+        hooks.add_test();
+    }
+
+    if !runtime.arounds.is_empty() && mode.is_async() {
+        return quote_spanned! { identifier.span() =>
+            compile_error!("`around` is not supported in async tests");
+        };
+    }
+
+    if !runtime.generators.is_empty() {
+        return property_test(identifier, runtime, mode, &test_declaration, &lets, content);
+    }
+
+    let steps = test_steps(identifier, runtime, mode, &lets, content);
+    let test = around_test(
+        identifier,
+        runtime,
+        quote_spanned! { identifier.span() =>
+            #steps
+
+            test_result_from_cases(test_cases)
+        },
+    );
+
+    let body = quote_spanned! { identifier.span() =>
+        let _current_test = lets_expect::__private::CurrentTest::enter(env!("CARGO_MANIFEST_DIR"), module_path!(), stringify!(#identifier));
+
+        #test
+    };
+
+    test_declaration.with_body(identifier, full_body(identifier, runtime, mode, body))
+}
+
+/// A test that runs with many generated values of the variables of a property-based `when`.
+/// The lets, befores, the expectations and afters run again for every value.
+fn property_test(
+    identifier: &Ident,
+    runtime: &Runtime,
+    mode: Mode,
+    test_declaration: &TestDeclaration,
+    lets: &[Local],
+    content: &TokenStream,
+) -> TokenStream {
+    if mode.is_async() {
+        return quote_spanned! { identifier.span() =>
+            compile_error!("Generators in `when` are not supported in async tests");
+        };
+    }
+
+    let names = runtime
+        .generators
+        .iter()
+        .map(|generator| ident_from_pat(&generator.pat).map(|ident| ident.to_string()))
+        .collect::<Result<Vec<String>, _>>();
+    let names = match names {
+        Ok(names) => names,
+        Err(_) => {
+            return quote_spanned! { identifier.span() =>
+                compile_error!("Expected an identifier in `when`");
+            }
+        }
+    };
+    let generators = runtime
+        .generators
+        .iter()
+        .map(|generator| &generator.init.as_ref().unwrap().1);
+    let pats = runtime.generators.iter().map(|generator| &generator.pat);
+    let indices = (0..runtime.generators.len()).map(Index::from);
+
+    let steps = test_steps(identifier, runtime, mode, lets, content);
+    let test = around_test(
+        identifier,
+        runtime,
+        quote_spanned! { identifier.span() =>
+            #(
+                #[allow(unused_variables)]
+                let #pats = values.#indices;
+            )*
+
+            #steps
+
+            test_cases
+        },
+    );
+
+    let body = quote_spanned! { identifier.span() =>
+        let _current_test = lets_expect::__private::CurrentTest::enter_property(env!("CARGO_MANIFEST_DIR"), module_path!(), stringify!(#identifier));
+
+        let generators = (#({
+            use lets_expect::generators::*;
+            #generators
+        },)*);
+
+        lets_expect::__private::run_property(&[#(#names),*], generators, |values| {
+            #test
+        })
+    };
+
+    test_declaration.with_body(identifier, full_body(identifier, runtime, mode, body))
+}
+
+/// The lets, befores, the expectations and the afters, leaving the executed test cases in
+/// `test_cases`.
+///
+/// A panic in any of them is reported as a failed test case naming where it happened. The afters
+/// still run, except for the ones using a let that panicked or a let defined after it. Panics can't
+/// be caught in async tests, there they unwind as usual and the afters don't run.
+fn test_steps(
+    identifier: &Ident,
+    runtime: &Runtime,
+    mode: Mode,
+    lets: &[Local],
+    content: &TokenStream,
+) -> TokenStream {
+    let befores = &runtime.befores;
+    let afters = &runtime.afters;
+
+    if mode.is_async() {
+        return quote_spanned! { identifier.span() =>
             #(#lets)*
 
             #(#befores)*
@@ -86,9 +208,243 @@ pub fn create_test(
             };
 
             #(#afters)*
+        };
+    }
 
-            test_result_from_cases(test_cases)
+    let subject_label = subject_label(identifier, runtime);
+    let whens = &runtime.whens;
+    let let_idents = lets
+        .iter()
+        .map(|r#let| ident_from_pat(&r#let.pat).unwrap())
+        .collect::<Vec<Ident>>();
+    let after_dependencies = afters
+        .iter()
+        .map(|after| {
+            block_dependencies(after)
+                .into_iter()
+                .filter(|dependency| let_idents.contains(dependency))
+                .collect::<HashSet<Ident>>()
+        })
+        .collect::<Vec<HashSet<Ident>>>();
+    // Befores and afters are numbered in the order they run in.
+    let numbered_afters = afters
+        .iter()
+        .enumerate()
+        .map(|(index, after)| (index + 1, after))
+        .collect::<Vec<(usize, &Block)>>();
+    let panicked = quote_spanned! { identifier.span() =>
+        break 'steps lets_expect::__private::test_cases_with_panics(#subject_label, vec![#(#whens),*], Vec::new(), panics)
+    };
+
+    let lets = lets.iter().enumerate().map(|(index, r#let)| {
+        let attrs = &r#let.attrs;
+        let pat = &r#let.pat;
+        let name = let_idents[index].to_string();
+        let init = &r#let.init.as_ref().unwrap().1;
+        let defined = &let_idents[..index];
+        let afters = numbered_afters
+            .iter()
+            .zip(after_dependencies.iter())
+            .filter(|(_, dependencies)| {
+                dependencies
+                    .iter()
+                    .all(|dependency| defined.contains(dependency))
+            })
+            .map(|(after, _)| *after);
+        let afters = run_afters(identifier, afters);
+
+        quote_spanned! { identifier.span() =>
+            #(#attrs)*
+            #[allow(clippy::redundant_closure)]
+            let #pat = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| #init)) {
+                Ok(value) => value,
+                Err(panic) => {
+                    #[allow(unused_mut)]
+                    let mut panics = vec![lets_expect::__private::TestPanic::new(lets_expect::__private::Phase::Let(#name), panic)];
+                    #afters
+                    #panicked
+                }
+            };
+        }
+    });
+    let afters = run_afters(identifier, numbered_afters.iter().copied());
+    let befores = befores.iter().enumerate().map(|(index, before)| {
+        let number = index + 1;
+
+        quote_spanned! { identifier.span() =>
+            if let Err(panic) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| #before)) {
+                #[allow(unused_mut)]
+                let mut panics = vec![lets_expect::__private::TestPanic::new(lets_expect::__private::Phase::Before(#number), panic)];
+                #afters
+                #panicked
+            }
+        }
+    });
+    // Subjects and assertions catch their own panics, what's left is a story's own statements.
+    let content_phase = if runtime.subject.is_some() {
+        quote_spanned! { identifier.span() => lets_expect::__private::Phase::Assertion }
+    } else {
+        quote_spanned! { identifier.span() => lets_expect::__private::Phase::Story }
+    };
+
+    quote_spanned! { identifier.span() =>
+        #[allow(clippy::never_loop)]
+        let test_cases = 'steps: loop {
+            #(#lets)*
+
+            #(#befores)*
+
+            let mut panics = Vec::new();
+            let test_cases = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| { #content })) {
+                Ok(test_cases) => test_cases,
+                Err(panic) => {
+                    panics.push(lets_expect::__private::TestPanic::new(#content_phase, panic));
+                    Vec::new()
+                }
+            };
+
+            #afters
+
+            break 'steps lets_expect::__private::test_cases_with_panics(#subject_label, vec![#(#whens),*], test_cases, panics);
+        };
+    }
+}
+
+/// Runs the numbered afters one by one, adding their panics to `panics`.
+fn run_afters<'a>(
+    identifier: &Ident,
+    afters: impl Iterator<Item = (usize, &'a Block)>,
+) -> TokenStream {
+    let afters = afters.map(|(number, after)| {
+        quote_spanned! { identifier.span() =>
+            if let Err(panic) = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| #after)) {
+                panics.push(lets_expect::__private::TestPanic::new(lets_expect::__private::Phase::After(#number), panic));
+            }
         }
+    });
+
+    quote_spanned! { identifier.span() =>
+        #(#afters)*
+    }
+}
+
+/// Runs `test` inside of the `around` hooks, the outer ones first. The hooks get a function
+/// running the test, which runs it only the first time it's called.
+fn around_test(identifier: &Ident, runtime: &Runtime, test: TokenStream) -> TokenStream {
+    if runtime.arounds.is_empty() {
+        return test;
+    }
+
+    let mut run = quote_spanned! { identifier.span() =>
+        || {
+            if let Some(test) = test.take() {
+                test_result = Some(test());
+            }
+        }
+    };
+    let mut call = TokenStream::new();
+    for around in runtime.arounds.iter().rev() {
+        call = quote_spanned! { identifier.span() =>
+            lets_expect::__private::run_around(#around, #run)
+        };
+        run = quote_spanned! { identifier.span() =>
+            || #call
+        };
+    }
+
+    quote_spanned! { identifier.span() =>
+        let mut test = Some(|| { #test });
+        let mut test_result = None;
+
+        #call;
+
+        test_result.expect("`around` should call the function running the test")
+    }
+}
+
+/// Wraps the body of a test with the hooks, skip conditions and pending expectations around it.
+fn full_body(identifier: &Ident, runtime: &Runtime, mode: Mode, body: TokenStream) -> TokenStream {
+    let before_alls = runtime.hooks.iter().map(Hooks::before_all_ident);
+    let body = quote_spanned! { identifier.span() =>
+        #(#before_alls();)*
+        #body
+    };
+    let body = skip_body(
+        identifier,
+        runtime,
+        pending_body(identifier, runtime, mode, body),
+    );
+
+    if runtime.hooks.is_empty() {
+        return body;
+    }
+
+    // Dropped when the test finishes, even if it panics.
+    let after_alls = runtime.hooks.iter().map(Hooks::after_all_ident);
+    quote_spanned! { identifier.span() =>
+        let _after_alls = (#(#after_alls(),)*);
+        #body
+    }
+}
+
+/// Checks the `skip_if` conditions and the tags selected in the environment before anything else
+/// runs. A skipped test passes, reporting why it was skipped.
+fn skip_body(identifier: &Ident, runtime: &Runtime, body: TokenStream) -> TokenStream {
+    let conditions = runtime.skips.iter().map(|skip| &skip.condition);
+    let reasons = runtime.skips.iter().map(|skip| &skip.reason);
+    let tags = runtime.tags.iter().map(Ident::to_string);
+    let subject_label = subject_label(identifier, runtime);
+    let whens = &runtime.whens;
+
+    quote_spanned! { identifier.span() =>
+        let skip_reason: Option<String> = #(if #conditions { Some(#reasons.to_string()) } else)* {
+            lets_expect::__private::skipped_by_tags(&[#(#tags),*])
+        };
+
+        if let Some(skip_reason) = skip_reason {
+            test_result_from_cases(vec![ExecutedTestCase::skipped(#subject_label.to_string(), vec![#(#whens),*], &skip_reason)])
+        } else {
+            #body
+        }
+    }
+}
+
+/// What a test expects, the subject or, in a story, the name of the story.
+fn subject_label(identifier: &Ident, runtime: &Runtime) -> String {
+    runtime.subject.as_ref().map_or_else(
+        || identifier.to_string(),
+        |subject| subject.1.to_token_stream().to_string(),
+    )
+}
+
+/// The body of a pending test passes when the original body fails or panics, and fails when it
+/// passes. Panics can't be caught in async tests, there only failed expectations count.
+fn pending_body(
+    identifier: &Ident,
+    runtime: &Runtime,
+    mode: Mode,
+    body: TokenStream,
+) -> TokenStream {
+    if !runtime.pending {
+        return body;
+    }
+
+    let result = match mode {
+        Mode::Test | Mode::PubMethod => quote_spanned! { identifier.span() =>
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| -> TestResult { #body }))
+        },
+        Mode::PubAsyncMethod => quote_spanned! { identifier.span() =>
+            Ok({ #body })
+        },
+        #[cfg(feature = "tokio")]
+        Mode::TokioTest => quote_spanned! { identifier.span() =>
+            Ok({ #body })
+        },
+    };
+
+    quote_spanned! { identifier.span() =>
+        let result: std::thread::Result<TestResult> = #result;
+        lets_expect::__private::pending_test_result(concat!(module_path!(), "::", stringify!(#identifier)), result)
     }
 }
 
@@ -111,22 +467,82 @@ fn recursive_dependencies(lets: &[Local], ident: &Ident, dependencies: &mut Hash
     }
 }
 
-fn test_declaration(identifier: &Ident, mode: Mode) -> TokenStream {
-    match mode {
+/// The signature of a test and whether it is expected to panic.
+struct TestDeclaration {
+    signature: TokenStream,
+    should_panic: bool,
+}
+
+impl TestDeclaration {
+    /// `#[should_panic]` tests can't return a `Result`, so their failures are turned into panics.
+    fn with_body(&self, identifier: &Ident, body: TokenStream) -> TokenStream {
+        let signature = &self.signature;
+
+        if self.should_panic {
+            quote_spanned! { identifier.span() =>
+                #signature {
+                    let result: Result<(), TestFailure> = { #body };
+                    if let Err(failure) = result {
+                        panic!("{:?}", failure);
+                    }
+                }
+            }
+        } else {
+            quote_spanned! { identifier.span() =>
+                #signature {
+                    #body
+                }
+            }
+        }
+    }
+}
+
+fn test_declaration(identifier: &Ident, mode: Mode, attributes: &[Attribute]) -> TestDeclaration {
+    let is_test = match mode {
+        Mode::Test => true,
+        Mode::PubMethod | Mode::PubAsyncMethod => false,
+        #[cfg(feature = "tokio")]
+        Mode::TokioTest => true,
+    };
+    // Methods are called by other tests, attributes only a test runner understands don't apply.
+    let attributes = attributes
+        .iter()
+        .filter(|attribute| is_test || !is_test_only(attribute))
+        .collect::<Vec<&Attribute>>();
+    let should_panic = is_test
+        && attributes
+            .iter()
+            .any(|attribute| is_should_panic(attribute));
+    let result = if should_panic {
+        TokenStream::new()
+    } else {
+        quote_spanned! { identifier.span() => -> Result<(), TestFailure> }
+    };
+
+    let signature = match mode {
         Mode::Test => quote_spanned! { identifier.span() =>
             #[test]
-            fn #identifier() -> Result<(), TestFailure>
+            #(#attributes)*
+            fn #identifier() #result
         },
         Mode::PubMethod => quote_spanned! { identifier.span() =>
-            pub fn #identifier() -> Result<(), TestFailure>
+            #(#attributes)*
+            pub fn #identifier() #result
         },
         Mode::PubAsyncMethod => quote_spanned! { identifier.span() =>
-            pub async fn #identifier() -> Result<(), TestFailure>
+            #(#attributes)*
+            pub async fn #identifier() #result
         },
         #[cfg(feature = "tokio")]
         Mode::TokioTest => quote_spanned! { identifier.span() =>
             #[tokio::test]
-            async fn #identifier() -> Result<(), TestFailure>
+            #(#attributes)*
+            async fn #identifier() #result
         },
+    };
+
+    TestDeclaration {
+        signature,
+        should_panic,
     }
 }
@@ -0,0 +1,377 @@
+use std::{fmt, fs, iter::Peekable, path::Path, str::Chars};
+
+use proc_macro2::Span;
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde_json::Value;
+use syn::{parse_quote, Expr, LitFloat, LitInt, LitStr};
+
+/// A row of a data file, its fields in the order they appear in the file.
+pub type Row = Vec<(String, Expr)>;
+
+/// Reads a JSON file containing an array of objects, or a CSV file with a header, into rows.
+pub fn read_rows(path: &Path) -> Result<Vec<Row>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("Unable to read {}: {}", path.display(), error))?;
+
+    let rows = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => parse_json(&content).map(|rows| {
+            rows.into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|(name, value)| (name, value_to_expr(&value)))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        }),
+        Some("csv") => parse_csv(&content).map(|rows| {
+            rows.into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|(name, field)| (name, Ok(csv_value(&field))))
+                        .collect()
+                })
+                .collect()
+        }),
+        _ => return Err("Expected a `.json` or a `.csv` file".to_string()),
+    }
+    .map_err(|error| format!("Malformed data file {}: {}", path.display(), error))?;
+
+    rows.into_iter()
+        .enumerate()
+        .map(|(index, row)| {
+            row.into_iter()
+                .map(|(name, expr)| {
+                    if syn::parse_str::<syn::Ident>(&name).is_err() {
+                        return Err(format!(
+                            "Field {:?} in row {} isn't a valid variable name",
+                            name,
+                            index + 1
+                        ));
+                    }
+                    expr.map(|expr| (name.clone(), expr)).map_err(|error| {
+                        format!("{} in field {:?} of row {}", error, name, index + 1)
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn value_to_expr(value: &Value) -> Result<Expr, String> {
+    // The literals don't appear in the source, see `expand_values` in `when.rs`.
+    let span = Span::call_site();
+
+    Ok(match value {
+        Value::Null => return Err("`null` values aren't supported".to_string()),
+        Value::Bool(value) => parse_quote! { #value },
+        Value::Number(number) => number_to_expr(&number.to_string(), number.is_f64()),
+        Value::String(value) => {
+            let literal = LitStr::new(value, span);
+            parse_quote! { #literal }
+        }
+        Value::Array(values) => {
+            let values = values
+                .iter()
+                .map(value_to_expr)
+                .collect::<Result<Vec<Expr>, String>>()?;
+            parse_quote! { [#(#values),*] }
+        }
+        Value::Object(_) => return Err("Nested objects aren't supported".to_string()),
+    })
+}
+
+fn number_to_expr(number: &str, float: bool) -> Expr {
+    let span = Span::call_site();
+    let (negative, digits) = match number.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, number),
+    };
+    let literal: Expr = if float {
+        let literal = LitFloat::new(&float_literal(digits), span);
+        parse_quote! { #literal }
+    } else {
+        let literal = LitInt::new(digits, span);
+        parse_quote! { #literal }
+    };
+
+    if negative {
+        parse_quote! { -#literal }
+    } else {
+        literal
+    }
+}
+
+/// `1e5` is a valid float in JSON, Rust needs `1.0e5` not to read it as an integer with a suffix.
+fn float_literal(digits: &str) -> String {
+    if digits.contains('.') {
+        digits.to_string()
+    } else {
+        let exponent = digits.find(['e', 'E']).unwrap();
+        format!("{}.0{}", &digits[..exponent], &digits[exponent..])
+    }
+}
+
+fn parse_json(content: &str) -> Result<Vec<Vec<(String, Value)>>, String> {
+    serde_json::from_str::<Vec<JsonRow>>(content)
+        .map(|rows| rows.into_iter().map(|row| row.0).collect())
+        .map_err(|error| error.to_string())
+}
+
+/// The fields of a JSON object in the order they appear in the file, which `serde_json::Map`
+/// only keeps with its `preserve_order` feature.
+struct JsonRow(Vec<(String, Value)>);
+
+impl<'de> Deserialize<'de> for JsonRow {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RowVisitor;
+
+        impl<'de> Visitor<'de> for RowVisitor {
+            type Value = JsonRow;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonRow, A::Error> {
+                let mut fields = Vec::new();
+                while let Some(field) = map.next_entry()? {
+                    fields.push(field);
+                }
+                Ok(JsonRow(fields))
+            }
+        }
+
+        deserializer.deserialize_map(RowVisitor)
+    }
+}
+
+/// The first record holds the names of the fields.
+fn parse_csv(content: &str) -> Result<Vec<Vec<(String, String)>>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    let header = reader.headers().map_err(|error| error.to_string())?.clone();
+    if header.is_empty() {
+        return Err("expected a header".to_string());
+    }
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(|error| error.to_string())?;
+            Ok(header
+                .iter()
+                .zip(record.iter())
+                .map(|(name, field)| (name.to_string(), field.to_string()))
+                .collect())
+        })
+        .collect()
+}
+
+/// CSV has no types, values that look like numbers or booleans become numbers and booleans.
+fn csv_value(field: &str) -> Expr {
+    match field {
+        "true" => parse_quote! { true },
+        "false" => parse_quote! { false },
+        _ if is_number(field) => number_to_expr(field, field.contains(['.', 'e', 'E'])),
+        _ => {
+            let literal = LitStr::new(field, Span::call_site());
+            parse_quote! { #literal }
+        }
+    }
+}
+
+/// Whether a value matches `-?[0-9][0-9_]*(\.[0-9]+)?([eE][+-]?[0-9]+)?`, so that it can be
+/// turned into a Rust literal. Values like `+5`, `.5` or `inf` aren't numbers.
+fn is_number(value: &str) -> bool {
+    let mut chars = value.strip_prefix('-').unwrap_or(value).chars().peekable();
+
+    if chars.next_if(char::is_ascii_digit).is_none() {
+        return false;
+    }
+    while chars
+        .next_if(|char| char.is_ascii_digit() || *char == '_')
+        .is_some()
+    {}
+
+    if chars.next_if_eq(&'.').is_some() && !digits(&mut chars) {
+        return false;
+    }
+    if chars.next_if(|char| matches!(char, 'e' | 'E')).is_some() {
+        chars.next_if(|char| matches!(char, '+' | '-'));
+        if !digits(&mut chars) {
+            return false;
+        }
+    }
+
+    chars.next().is_none()
+}
+
+/// Consumes a run of digits, returning whether there was at least one.
+fn digits(chars: &mut Peekable<Chars>) -> bool {
+    let mut found = false;
+    while chars.next_if(char::is_ascii_digit).is_some() {
+        found = true;
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::ToTokens;
+    use serde_json::json;
+    use std::env;
+
+    fn tokens(expr: &Expr) -> String {
+        expr.to_token_stream().to_string()
+    }
+
+    fn write_file(name: &str, content: &str) -> std::path::PathBuf {
+        let path = env::temp_dir().join(format!("lets_expect_{}_{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_is_number() {
+        for value in ["0", "-12", "1_000", "2.5", "-0.5", "1e5", "1E-5", "2.5e+3"] {
+            assert!(is_number(value), "{} should be a number", value);
+        }
+        for value in [
+            "", "-", "+5", ".5", "5.", "1e", "1e+", "-inf", "-nan", "NaN", "0x10", "1.5.5", "_1",
+            "12a",
+        ] {
+            assert!(!is_number(value), "{} shouldn't be a number", value);
+        }
+    }
+
+    #[test]
+    fn test_parse_json_keeps_the_order_of_fields() {
+        assert_eq!(
+            parse_json(r#"[{ "b": 1, "a": "x" }, {}]"#),
+            Ok(vec![
+                vec![("b".to_string(), json!(1)), ("a".to_string(), json!("x"))],
+                Vec::new(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_json_errors() {
+        for (content, error) in [
+            (
+                "[1]",
+                "invalid type: integer `1`, expected an object at line 1 column 2",
+            ),
+            (
+                "{}",
+                "invalid type: map, expected a sequence at line 1 column 0",
+            ),
+            ("[{ \"a\": }]", "expected value at line 1 column 9"),
+        ] {
+            assert_eq!(parse_json(content), Err(error.to_string()), "{}", content);
+        }
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let row = |fields: [(&str, &str); 2]| {
+            fields
+                .iter()
+                .map(|(name, field)| (name.to_string(), field.to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            parse_csv("a, b\n1, -2.5\n\n\"x, y\",\"say \"\"hi\"\"\nand bye\"\r\n"),
+            Ok(vec![
+                row([("a", "1"), ("b", "-2.5")]),
+                row([("a", "x, y"), ("b", "say \"hi\"\nand bye")]),
+            ])
+        );
+        assert_eq!(parse_csv(""), Err("expected a header".to_string()));
+        assert!(parse_csv("a,b\n1,2\n1\n").is_err());
+    }
+
+    #[test]
+    fn test_csv_value() {
+        for (field, expected) in [
+            ("5", "5"),
+            ("-1_000", "- 1_000"),
+            ("2.5e3", "2.5e3"),
+            ("-1e5", "- 1.0e5"),
+            ("true", "true"),
+            ("+5", "\"+5\""),
+            ("", "\"\""),
+        ] {
+            assert_eq!(tokens(&csv_value(field)), expected, "{}", field);
+        }
+    }
+
+    #[test]
+    fn test_value_to_expr() {
+        for (value, expected) in [
+            (json!(5), "5"),
+            (json!(-5), "- 5"),
+            (json!(2.5), "2.5"),
+            (json!(-1e5), "- 100000.0"),
+            (json!(1e20), "1.0e+20"),
+            (json!("a\"b"), "\"a\\\"b\""),
+            (json!(false), "false"),
+            (json!([1, []]), "[1 , []]"),
+        ] {
+            assert_eq!(tokens(&value_to_expr(&value).unwrap()), expected);
+        }
+        assert_eq!(
+            value_to_expr(&json!({})),
+            Err("Nested objects aren't supported".to_string())
+        );
+        assert_eq!(
+            value_to_expr(&json!([1, null])),
+            Err("`null` values aren't supported".to_string())
+        );
+    }
+
+    #[test]
+    fn test_read_rows_errors() {
+        let malformed = write_file("malformed.json", "[{ \"a\": 1 ");
+        assert_eq!(
+            read_rows(&malformed),
+            Err(format!(
+                "Malformed data file {}: EOF while parsing an object at line 1 column 10",
+                malformed.display()
+            ))
+        );
+
+        let invalid_name = write_file("invalid_name.csv", "a b\n1\n");
+        assert_eq!(
+            read_rows(&invalid_name),
+            Err("Field \"a b\" in row 1 isn't a valid variable name".to_string())
+        );
+
+        let nested = write_file("nested.json", "[{ \"a\": {} }]");
+        assert_eq!(
+            read_rows(&nested),
+            Err("Nested objects aren't supported in field \"a\" of row 1".to_string())
+        );
+
+        let null = write_file("null.json", "[{ \"a\": 1 }, { \"a\": 2, \"b\": null }]");
+        assert_eq!(
+            read_rows(&null),
+            Err("`null` values aren't supported in field \"b\" of row 2".to_string())
+        );
+
+        let extension = write_file("cases.txt", "");
+        assert_eq!(
+            read_rows(&extension),
+            Err("Expected a `.json` or a `.csv` file".to_string())
+        );
+
+        let missing = env::temp_dir().join("lets_expect_missing.json");
+        assert!(read_rows(&missing)
+            .unwrap_err()
+            .starts_with(&format!("Unable to read {}: ", missing.display())));
+    }
+}
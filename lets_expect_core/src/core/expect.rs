@@ -1,13 +1,19 @@
 use crate::utils::to_ident::expr_to_ident;
 
-use super::{context::Context, create_module::create_module, keyword, runtime::Runtime};
+use super::{
+    attributes::{module_attributes, test_attributes},
+    context::Context,
+    create_module::create_module,
+    keyword,
+    runtime::Runtime,
+};
 use proc_macro2::{Ident, TokenStream};
 use syn::{
     braced, parenthesized,
     parse::{Parse, ParseStream},
     spanned::Spanned,
     token::Brace,
-    Error, Expr, Token,
+    Attribute, Error, Expr, Token,
 };
 
 pub struct Expect {
@@ -61,20 +67,32 @@ impl Parse for Expect {
 }
 
 impl Expect {
-    pub fn to_tokens(&self, keyword: &keyword::expect, runtime: &Runtime) -> TokenStream {
-        let runtime = runtime.extend(
-            Some((self.mutable, self.subject.clone())),
-            &Vec::new(),
-            &Vec::new(),
-            &Vec::new(),
-            None,
-        );
+    pub fn to_tokens(
+        &self,
+        keyword: &keyword::expect,
+        attributes: &[Attribute],
+        runtime: &Runtime,
+    ) -> TokenStream {
+        let runtime = runtime
+            .extend(
+                Some((self.mutable, self.subject.clone())),
+                &Vec::new(),
+                &Vec::new(),
+                &Vec::new(),
+                None,
+            )
+            .add_attributes(&test_attributes(attributes));
         let context = self.context.to_tokens(&keyword.span(), &runtime);
         let module_identifier = Ident::new(
             &format!("expect_{}", self.subject_identifier),
             self.subject_identifier.span(),
         );
 
-        create_module(&keyword.span(), &module_identifier, &context)
+        create_module(
+            &keyword.span(),
+            &module_identifier,
+            &module_attributes(attributes),
+            &context,
+        )
     }
 }
@@ -1,17 +1,24 @@
 use super::{expect::Expect, keyword, runtime::Runtime};
 use proc_macro2::TokenStream;
+use syn::Attribute;
 
 pub struct ExpectBlock {
     keyword: keyword::expect,
     expect: Expect,
+    attributes: Vec<Attribute>,
 }
 
 impl ExpectBlock {
-    pub fn new(keyword: keyword::expect, expect: Expect) -> Self {
-        Self { keyword, expect }
+    pub fn new(keyword: keyword::expect, expect: Expect, attributes: Vec<Attribute>) -> Self {
+        Self {
+            keyword,
+            expect,
+            attributes,
+        }
     }
 
     pub fn to_tokens(&self, runtime: &Runtime) -> TokenStream {
-        self.expect.to_tokens(&self.keyword, runtime)
+        self.expect
+            .to_tokens(&self.keyword, &self.attributes, runtime)
     }
 }
@@ -1,17 +1,33 @@
 use syn::custom_keyword;
 
 custom_keyword!(after);
+custom_keyword!(after_all);
+custom_keyword!(around);
 custom_keyword!(before);
+custom_keyword!(before_all);
 custom_keyword!(expect);
 custom_keyword!(when);
+custom_keyword!(each);
 custom_keyword!(to);
 custom_keyword!(story);
+custom_keyword!(shared);
+custom_keyword!(behaves_like);
+custom_keyword!(pending);
+custom_keyword!(skip_if);
+custom_keyword!(tag);
+custom_keyword!(xto);
+custom_keyword!(xwhen);
+custom_keyword!(xexpect);
 custom_keyword!(have);
 custom_keyword!(make);
 custom_keyword!(change);
 custom_keyword!(not_change);
 custom_keyword!(panic);
 custom_keyword!(not_panic);
+custom_keyword!(panic_with);
+custom_keyword!(panic_with_payload);
 custom_keyword!(be_some_and);
 custom_keyword!(be_ok_and);
 custom_keyword!(be_err_and);
+custom_keyword!(match_pattern);
+custom_keyword!(and);
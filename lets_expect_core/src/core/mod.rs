@@ -1,11 +1,18 @@
+mod after_all_block;
 mod after_block;
+mod around_block;
+mod attributes;
+mod before_all_block;
 mod before_block;
 mod create_module;
 mod create_test;
+mod data_file;
 mod expect;
 mod expect_block;
 mod ident_from_pat;
 mod mode;
+mod module_hooks;
+mod skip_if;
 mod story;
 mod story_block;
 mod story_expect_to;
@@ -17,4 +24,6 @@ mod when_block;
 
 pub mod context;
 pub(crate) mod keyword;
+pub mod pattern_guard;
 pub mod runtime;
+pub mod shared;
@@ -6,3 +6,14 @@ pub enum Mode {
     #[cfg(feature = "tokio")]
     TokioTest,
 }
+
+impl Mode {
+    pub fn is_async(self) -> bool {
+        match self {
+            Self::Test | Self::PubMethod => false,
+            Self::PubAsyncMethod => true,
+            #[cfg(feature = "tokio")]
+            Self::TokioTest => true,
+        }
+    }
+}
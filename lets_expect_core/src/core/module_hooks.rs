@@ -0,0 +1,170 @@
+use std::{cell::Cell, rc::Rc};
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote_spanned};
+use syn::{parse_quote, Local, Stmt, Type};
+
+use super::{after_all_block::AfterAllBlock, before_all_block::BeforeAllBlock};
+
+/// The `before_all` and `after_all` hooks of a module, as seen by the tests inside of it.
+///
+/// Each module with hooks gets an index, unique among its parents, so that its functions can be
+/// called from the nested modules which import everything from their parents.
+#[derive(Clone, Debug)]
+pub struct Hooks {
+    index: usize,
+    /// Number of tests created in the module so far, including the ignored ones and the ones a
+    /// `#[cfg]` removes later. `after_all` runs after the last of them if all of them run.
+    tests: Rc<Cell<usize>>,
+    /// Variables bound to the values of `before_all` blocks.
+    values: Vec<(Ident, Type)>,
+}
+
+impl Hooks {
+    pub fn new(index: usize, before_alls: &[&BeforeAllBlock]) -> Self {
+        Self {
+            index,
+            tests: Rc::new(Cell::new(0)),
+            values: before_alls
+                .iter()
+                .filter_map(|before_all| before_all.binding.clone())
+                .collect(),
+        }
+    }
+
+    pub fn add_test(&self) {
+        self.tests.set(self.tests.get() + 1);
+    }
+
+    pub fn before_all_ident(&self) -> Ident {
+        format_ident!("lets_expect_before_all_{}", self.index)
+    }
+
+    pub fn after_all_ident(&self) -> Ident {
+        format_ident!("lets_expect_after_all_{}", self.index)
+    }
+
+    fn after_all_hook_ident(&self) -> Ident {
+        format_ident!("lets_expect_after_all_hook_{}", self.index)
+    }
+
+    fn hooks_ident(&self) -> Ident {
+        format_ident!("LETS_EXPECT_HOOKS_{}", self.index)
+    }
+
+    fn value_ident(&self, name: &Ident) -> Ident {
+        format_ident!("lets_expect_before_all_{}_{}", self.index, name)
+    }
+
+    fn value_static_ident(&self, name: &Ident) -> Ident {
+        format_ident!(
+            "LETS_EXPECT_BEFORE_ALL_{}_{}",
+            self.index,
+            name.to_string().to_uppercase()
+        )
+    }
+
+    /// Lets reading the values of `before_all` blocks with a binding.
+    pub fn lets(&self) -> Vec<Local> {
+        self.values
+            .iter()
+            .map(|(name, ty)| {
+                let value_ident = self.value_ident(name);
+                let stmt: Stmt = parse_quote! { let #name: &'static #ty = #value_ident(); };
+                match stmt {
+                    Stmt::Local(local) => local,
+                    _ => unreachable!("Expected a `let` statement"),
+                }
+            })
+            .collect()
+    }
+
+    /// The state of the hooks and the functions the tests call to run them. Has to be created
+    /// after all the tests of the module, once their number is known.
+    ///
+    /// `before_all` blocks can use the values of the `before_all` blocks of the parent modules,
+    /// which run first, and of the ones above them.
+    pub fn to_tokens(
+        &self,
+        span: &Span,
+        parents: &[Self],
+        before_alls: &[&BeforeAllBlock],
+        after_alls: &[&AfterAllBlock],
+    ) -> TokenStream {
+        let hooks_ident = self.hooks_ident();
+        let before_all_ident = self.before_all_ident();
+        let after_all_ident = self.after_all_ident();
+        let after_all_hook_ident = self.after_all_hook_ident();
+        let tests = self.tests.get();
+
+        let parent_values = parents.iter().flat_map(|parent| {
+            parent.values.iter().map(move |(name, ty)| {
+                let value_ident = parent.value_ident(name);
+                quote_spanned! { name.span() =>
+                    #[allow(unused_variables)]
+                    let #name: &'static #ty = #value_ident();
+                }
+            })
+        });
+
+        let values = before_alls
+            .iter()
+            .filter_map(|before_all| before_all.binding.as_ref())
+            .map(|(name, ty)| {
+                let value_ident = self.value_ident(name);
+                let value_static_ident = self.value_static_ident(name);
+
+                quote_spanned! { name.span() =>
+                    static #value_static_ident: std::sync::OnceLock<#ty> = std::sync::OnceLock::new();
+
+                    #[allow(dead_code)]
+                    pub fn #value_ident() -> &'static #ty {
+                        #value_static_ident.get().expect("`before_all` should run before the value is read")
+                    }
+                }
+            });
+
+        let before_alls = before_alls.iter().map(|before_all| {
+            let block = &before_all.before_all;
+
+            match &before_all.binding {
+                Some((name, ty)) => {
+                    let value_static_ident = self.value_static_ident(name);
+
+                    quote_spanned! { name.span() =>
+                        #[allow(unused_variables)]
+                        let #name: &'static #ty = #value_static_ident.get_or_init(|| #block);
+                    }
+                }
+                None => quote_spanned! { *span =>
+                    #[allow(unused_braces, clippy::let_unit_value)]
+                    let _ = #block;
+                },
+            }
+        });
+        let after_alls = after_alls.iter().map(|after_all| &after_all.after_all);
+
+        quote_spanned! { *span =>
+            static #hooks_ident: lets_expect::__private::ModuleHooks = lets_expect::__private::ModuleHooks::new(#after_all_hook_ident);
+
+            #(#values)*
+
+            #[allow(dead_code)]
+            pub fn #before_all_ident() {
+                #hooks_ident.before_all(|| {
+                    #(#parent_values)*
+                    #(#before_alls)*
+                });
+            }
+
+            fn #after_all_hook_ident() {
+                #(#after_alls)*
+            }
+
+            #[allow(dead_code)]
+            pub fn #after_all_ident() -> lets_expect::__private::AfterAllGuard {
+                lets_expect::__private::AfterAllGuard::new(&#hooks_ident, #tests)
+            }
+        }
+    }
+}
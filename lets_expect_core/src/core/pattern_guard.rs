@@ -0,0 +1,65 @@
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream, Parser},
+    Expr, Token,
+};
+
+use crate::utils::pattern_bindings::{
+    parse_pattern, pattern_bindings, with_binding_values, PatternBinding,
+};
+
+/// The guard of `match_pattern!(pattern if guard)`, given as `path; pattern; guard` where `path`
+/// leads to the `Binding` helpers. It's evaluated with the variables bound by the pattern, so
+/// they can be compared by value like in a regular `match`.
+pub struct PatternGuard {
+    path: TokenStream,
+    bindings: Vec<PatternBinding>,
+    guard: Expr,
+}
+
+impl Parse for PatternGuard {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = tokens_until_semicolon(input)?;
+        input.parse::<Token![;]>()?;
+        // `macro_rules!` passes the pattern in invisible groups, which patterns can't be parsed from.
+        let pattern = parse_pattern.parse2(flatten_groups(tokens_until_semicolon(input)?))?;
+        input.parse::<Token![;]>()?;
+        let guard = input.parse()?;
+
+        Ok(Self {
+            path,
+            bindings: pattern_bindings(&pattern),
+            guard,
+        })
+    }
+}
+
+impl PatternGuard {
+    pub fn to_tokens(&self) -> TokenStream {
+        let guard = &self.guard;
+        with_binding_values(&self.path, &self.bindings, quote! { #guard })
+    }
+}
+
+fn tokens_until_semicolon(input: ParseStream) -> syn::Result<TokenStream> {
+    let mut tokens = TokenStream::new();
+
+    while !input.is_empty() && !input.peek(Token![;]) {
+        tokens.extend([input.parse::<TokenTree>()?]);
+    }
+
+    Ok(tokens)
+}
+
+fn flatten_groups(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                flatten_groups(group.stream())
+            }
+            token => TokenStream::from(token),
+        })
+        .collect()
+}
@@ -1,15 +1,31 @@
-use syn::{Block, Expr, Local};
+use proc_macro2::{Ident, TokenStream};
+use syn::{Attribute, Block, Expr, ExprClosure, Local};
 
-use super::mode::Mode;
+use super::{
+    attributes::{is_tag, tags},
+    ident_from_pat::ident_from_pat,
+    mode::Mode,
+    module_hooks::Hooks,
+    shared::Shared,
+    skip_if::SkipIf,
+};
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Runtime {
     pub subject: Option<(bool, Expr)>,
     pub lets: Vec<Local>,
+    pub generators: Vec<Local>,
     pub befores: Vec<Block>,
     pub afters: Vec<Block>,
     pub mode: Option<Mode>,
     pub whens: Vec<String>,
+    pub shared: Vec<(Ident, TokenStream)>,
+    pub attributes: Vec<Attribute>,
+    pub pending: bool,
+    pub skips: Vec<SkipIf>,
+    pub tags: Vec<Ident>,
+    pub hooks: Vec<Hooks>,
+    pub arounds: Vec<ExprClosure>,
 }
 
 impl Runtime {
@@ -53,7 +69,7 @@ impl Runtime {
             befores: new_befores,
             afters: new_afters,
             mode: new_mode,
-            whens: self.whens.clone(),
+            ..self.clone()
         }
     }
 
@@ -62,12 +78,8 @@ impl Runtime {
         new_whens.push(when);
 
         Self {
-            subject: self.subject.clone(),
-            lets: self.lets.clone(),
-            befores: self.befores.clone(),
-            afters: self.afters.clone(),
-            mode: self.mode,
             whens: new_whens,
+            ..self.clone()
         }
     }
 
@@ -76,12 +88,116 @@ impl Runtime {
         new_lets.extend(lets.to_vec());
 
         Self {
-            subject: self.subject.clone(),
             lets: new_lets,
-            befores: self.befores.clone(),
-            afters: self.afters.clone(),
-            mode: self.mode,
-            whens: self.whens.clone(),
+            ..self.clone()
+        }
+    }
+
+    /// Variables of a property-based `when`, each initialized with a generator. They replace any
+    /// `let` of the same name defined so far.
+    pub fn add_generators(&self, generators: &[Local]) -> Self {
+        let names = generators
+            .iter()
+            .filter_map(|generator| ident_from_pat(&generator.pat).ok())
+            .collect::<Vec<_>>();
+        let mut new_generators = self.generators.clone();
+        new_generators.extend(generators.to_vec());
+
+        Self {
+            lets: self
+                .lets
+                .iter()
+                .filter(|r#let| {
+                    !matches!(ident_from_pat(&r#let.pat), Ok(ident) if names.contains(&ident))
+                })
+                .cloned()
+                .collect(),
+            generators: new_generators,
+            ..self.clone()
+        }
+    }
+
+    /// Shared contexts defined in a context, available to it and to all of its children.
+    pub fn add_shared(&self, shared: &[Shared]) -> Self {
+        let mut new_shared = self.shared.clone();
+        new_shared.extend(
+            shared
+                .iter()
+                .map(|shared| (shared.identifier.clone(), shared.content.clone())),
+        );
+
+        Self {
+            shared: new_shared,
+            ..self.clone()
+        }
+    }
+
+    /// The closest shared context with the given name.
+    pub fn shared(&self, name: &Ident) -> Option<&TokenStream> {
+        self.shared
+            .iter()
+            .rev()
+            .find(|(identifier, _)| identifier == name)
+            .map(|(_, content)| content)
+    }
+
+    /// Attributes put on every test created in a context. Tags, `#[tag(...)]`, are inherited by
+    /// the tests without becoming attributes.
+    pub fn add_attributes(&self, attributes: &[Attribute]) -> Self {
+        let mut new_attributes = self.attributes.clone();
+        let mut new_tags = self.tags.clone();
+
+        for attribute in attributes {
+            if is_tag(attribute) {
+                new_tags.extend(tags(attribute).unwrap_or_default());
+            } else {
+                new_attributes.push(attribute.clone());
+            }
+        }
+
+        Self {
+            attributes: new_attributes,
+            tags: new_tags,
+            ..self.clone()
+        }
+    }
+
+    /// Tests of a pending runtime are expected to fail.
+    pub fn pending(&self) -> Self {
+        Self {
+            pending: true,
+            ..self.clone()
+        }
+    }
+
+    pub fn add_skips(&self, skips: &[SkipIf]) -> Self {
+        let mut new_skips = self.skips.clone();
+        new_skips.extend(skips.to_vec());
+
+        Self {
+            skips: new_skips,
+            ..self.clone()
+        }
+    }
+
+    pub fn add_hooks(&self, hooks: &[Hooks]) -> Self {
+        let mut new_hooks = self.hooks.clone();
+        new_hooks.extend(hooks.to_vec());
+
+        Self {
+            hooks: new_hooks,
+            ..self.clone()
+        }
+    }
+
+    /// `around` hooks, the outer ones first.
+    pub fn add_arounds(&self, arounds: &[ExprClosure]) -> Self {
+        let mut new_arounds = self.arounds.clone();
+        new_arounds.extend(arounds.to_vec());
+
+        Self {
+            arounds: new_arounds,
+            ..self.clone()
         }
     }
 }
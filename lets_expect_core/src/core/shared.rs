@@ -0,0 +1,253 @@
+use proc_macro2::{Delimiter, Group, Ident, Span, TokenStream, TokenTree};
+use quote::quote;
+use syn::{braced, parse::Parse};
+
+use super::context::Context;
+
+const INCLUDE_SHARED: &str = "include_shared";
+const MODES: [&str; 4] = ["test", "method", "method_async", "tokio_test"];
+
+/// `shared name { ... }`, a group of lets, hooks and expectations that can be included in any
+/// context with `behaves_like name`.
+///
+/// The content is kept as tokens and parsed again wherever it's included.
+pub struct Shared {
+    pub identifier: Ident,
+    pub content: TokenStream,
+}
+
+impl Parse for Shared {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let identifier = input.parse::<Ident>()?;
+        let content;
+        braced!(content in input);
+        let content = content.parse::<TokenStream>()?;
+
+        // Reports syntax errors even if the shared context is never included.
+        syn::parse2::<Context>(replace_includes(content.clone()))?;
+
+        Ok(Self {
+            identifier,
+            content,
+        })
+    }
+}
+
+/// The definitions of `lets_expect_shared!`.
+pub struct SharedMacros {
+    shared: Vec<Shared>,
+}
+
+impl Parse for SharedMacros {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut shared = Vec::new();
+        while !input.is_empty() {
+            shared.push(input.parse()?);
+        }
+
+        Ok(Self { shared })
+    }
+}
+
+impl SharedMacros {
+    /// A `macro_rules!` for every shared context. Called with the input of `lets_expect!`, it
+    /// calls `lets_expect!` again with the shared context prepended as `@shared name { ... }`.
+    pub fn to_tokens(&self) -> TokenStream {
+        self.shared
+            .iter()
+            .map(|shared| {
+                let identifier = &shared.identifier;
+                let content = &shared.content;
+
+                quote! {
+                    #[macro_export]
+                    macro_rules! #identifier {
+                        ($($input:tt)*) => {
+                            ::lets_expect::lets_expect! {
+                                @shared #identifier { #content }
+                                $($input)*
+                            }
+                        };
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Result of resolving the `include_shared!(name)` items of a `lets_expect!` input.
+pub enum IncludedShared {
+    /// All included shared contexts are known, the input with them turned into `shared` and
+    /// `behaves_like` items.
+    Expanded(TokenStream),
+    /// A call to the macro generated for a shared context that isn't known yet. It calls
+    /// `lets_expect!` again with the shared context's tokens.
+    Missing(TokenStream),
+}
+
+/// A procedural macro can't expand the macros in its input, so the tokens of shared contexts
+/// defined with `lets_expect_shared!` are gathered by having their macros call `lets_expect!`
+/// again, one shared context at a time.
+pub fn include_shared(input: TokenStream) -> IncludedShared {
+    let mut tokens = input.clone().into_iter().peekable();
+    let mut definitions: Vec<(Ident, TokenStream)> = Vec::new();
+
+    loop {
+        let mut lookahead = tokens.clone();
+        match (
+            lookahead.next(),
+            lookahead.next(),
+            lookahead.next(),
+            lookahead.next(),
+        ) {
+            (
+                Some(TokenTree::Punct(at)),
+                Some(TokenTree::Ident(shared)),
+                Some(TokenTree::Ident(name)),
+                Some(TokenTree::Group(content)),
+            ) if at.as_char() == '@'
+                && shared == "shared"
+                && content.delimiter() == Delimiter::Brace =>
+            {
+                definitions.push((name, content.stream()));
+                tokens = lookahead;
+            }
+            _ => break,
+        }
+    }
+
+    let rest = tokens.collect::<TokenStream>();
+
+    let mut includes = Vec::new();
+    find_includes(&rest, &mut includes);
+    for (_, content) in &definitions {
+        find_includes(content, &mut includes);
+    }
+
+    if let Some((path, _)) = includes
+        .iter()
+        .find(|(_, name)| !definitions.iter().any(|(definition, _)| definition == name))
+    {
+        return IncludedShared::Missing(quote! { #path ! { #input } });
+    }
+
+    // The tokens of a shared context come from a `macro_rules!` and would be hygienic. Resolving
+    // them where the `lets_expect!` input includes them lets them use its variables. Includes in
+    // the input itself come first.
+    let site = includes
+        .first()
+        .map_or_else(Span::call_site, |(_, included)| included.span());
+    let shared = definitions.into_iter().map(|(name, content)| {
+        let name = Ident::new(&name.to_string(), site);
+        let content = replace_includes(resolve_at(content, site));
+
+        quote! { shared #name { #content } }
+    });
+
+    // The mode has to stay in front of the shared contexts, anything else starting with `#`
+    // belongs to the item after it.
+    let mut rest = rest.into_iter();
+    let mut mode = TokenStream::new();
+    let mut lookahead = rest.clone();
+    if let (Some(TokenTree::Punct(hash)), Some(TokenTree::Ident(ident))) =
+        (lookahead.next(), lookahead.next())
+    {
+        if hash.as_char() == '#' && MODES.contains(&ident.to_string().as_str()) {
+            mode.extend(rest.next());
+            mode.extend(rest.next());
+        }
+    }
+    let rest = replace_includes(rest.collect());
+
+    IncludedShared::Expanded(quote! {
+        #mode
+        #(#shared)*
+        #rest
+    })
+}
+
+/// Paths and names of the shared contexts included with `include_shared!(path)`.
+fn find_includes(tokens: &TokenStream, includes: &mut Vec<(TokenStream, Ident)>) {
+    let tokens = tokens.clone().into_iter().collect::<Vec<TokenTree>>();
+
+    for (index, token) in tokens.iter().enumerate() {
+        match (token, tokens.get(index + 1), tokens.get(index + 2)) {
+            (
+                TokenTree::Ident(ident),
+                Some(TokenTree::Punct(bang)),
+                Some(TokenTree::Group(path)),
+            ) if ident == INCLUDE_SHARED && bang.as_char() == '!' => {
+                if let Some(name) = last_ident(&path.stream()) {
+                    let name = Ident::new(&name.to_string(), ident.span());
+                    includes.push((path.stream(), name));
+                }
+            }
+            (TokenTree::Group(group), _, _) => find_includes(&group.stream(), includes),
+            _ => {}
+        }
+    }
+}
+
+fn last_ident(path: &TokenStream) -> Option<Ident> {
+    path.clone()
+        .into_iter()
+        .fold(None, |last, token| match token {
+            TokenTree::Ident(ident) => Some(ident),
+            _ => last,
+        })
+}
+
+/// Turns `include_shared!(path::name)` into `behaves_like name`.
+fn replace_includes(tokens: TokenStream) -> TokenStream {
+    let mut result = Vec::new();
+    let mut tokens = tokens.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) if ident == INCLUDE_SHARED => {
+                let mut lookahead = tokens.clone();
+                match (lookahead.next(), lookahead.next()) {
+                    (Some(TokenTree::Punct(bang)), Some(TokenTree::Group(path)))
+                        if bang.as_char() == '!' =>
+                    {
+                        match last_ident(&path.stream()) {
+                            Some(name) => {
+                                let behaves_like = Ident::new("behaves_like", ident.span());
+                                let name = Ident::new(&name.to_string(), ident.span());
+                                result.extend(quote! { #behaves_like #name });
+                            }
+                            None => result.push(TokenTree::Ident(ident)),
+                        }
+                        tokens = lookahead;
+                    }
+                    _ => result.push(TokenTree::Ident(ident)),
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(group.delimiter(), replace_includes(group.stream()));
+                replaced.set_span(group.span());
+                result.push(TokenTree::Group(replaced));
+            }
+            token => result.push(token),
+        }
+    }
+
+    result.into_iter().collect()
+}
+
+fn resolve_at(tokens: TokenStream, site: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Group(group) => {
+                let mut resolved = Group::new(group.delimiter(), resolve_at(group.stream(), site));
+                resolved.set_span(group.span().resolved_at(site));
+                TokenTree::Group(resolved)
+            }
+            mut token => {
+                token.set_span(token.span().resolved_at(site));
+                token
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,25 @@
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    Expr, LitStr, Token,
+};
+
+/// `skip_if(condition, "reason")`, checked when a test starts, before any `let` is evaluated.
+#[derive(Clone, Debug)]
+pub struct SkipIf {
+    pub condition: Expr,
+    pub reason: LitStr,
+}
+
+impl Parse for SkipIf {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        parenthesized!(content in input);
+
+        let condition = content.parse::<Expr>()?;
+        content.parse::<Token![,]>()?;
+        let reason = content.parse::<LitStr>()?;
+
+        Ok(Self { condition, reason })
+    }
+}
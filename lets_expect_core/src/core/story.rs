@@ -75,10 +75,16 @@ impl Story {
                 },
             );
 
+        // A failed expectation breaks out of the loop, the rest of the story doesn't run.
         let content = quote_spanned! { self.identifier.span() =>
-            let mut test_cases = Vec::new();
+            #[allow(clippy::never_loop)]
+            let test_cases = loop {
+                let mut test_cases = Vec::new();
 
-            #(#elements)*
+                #(#elements)*
+
+                break test_cases;
+            };
 
             test_cases
         };
@@ -1,21 +1,25 @@
 use proc_macro2::TokenStream;
+use syn::Attribute;
 
 use super::{keyword, runtime::Runtime, story::Story};
 
 pub struct StoryBlock {
     pub _keyword: keyword::story,
     pub story: Story,
+    pub attributes: Vec<Attribute>,
 }
 
 impl StoryBlock {
-    pub fn new(keyword: keyword::story, story: Story) -> Self {
+    pub fn new(keyword: keyword::story, story: Story, attributes: Vec<Attribute>) -> Self {
         Self {
             _keyword: keyword,
             story,
+            attributes,
         }
     }
 
     pub fn to_tokens(&self, runtime: &Runtime) -> TokenStream {
-        self.story.to_tokens(runtime)
+        self.story
+            .to_tokens(&runtime.add_attributes(&self.attributes))
     }
 }
@@ -45,7 +45,7 @@ impl Parse for StoryExpectTo {
         let to_keyword = input.parse::<keyword::to>()?;
 
         let to = input.parse::<To>()?;
-        let to = ToBlock::new(to_keyword, to);
+        let to = ToBlock::new(to_keyword, to, Vec::new());
 
         Ok(Self::new(keyword, subject, mutable, to))
     }
@@ -70,7 +70,7 @@ impl StoryExpectTo {
                 test_cases.push(test_case);
 
                 if failed {
-                    return test_result_from_cases(test_cases);
+                    break test_cases;
                 }
             },
             dependencies,
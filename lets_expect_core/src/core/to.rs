@@ -12,7 +12,7 @@ use crate::expectations::expectation_tokens::AssertionTokens;
 use crate::utils::expr_dependencies::expr_dependencies;
 use crate::utils::mutable_token::mutable_token;
 
-use super::runtime::Runtime;
+use super::{mode::Mode, runtime::Runtime};
 use crate::expectations::expectation_type::ExpectationType;
 use quote::{quote, quote_spanned, ToTokens};
 
@@ -54,7 +54,8 @@ impl To {
         let before_subject = expectation_tokens.before_subject_evaluation;
 
         let subject_label = subject.1.to_token_stream().to_string();
-        let subject_tokens = self.subject_tokens(subject, &identifier, self.expectation.is_panic());
+        let subject_tokens = self.subject_tokens(subject, &identifier);
+        let mutable_token = mutable_token(subject.0, &subject.1.span());
         let subject_dependencies = expr_dependencies(&subject.1);
 
         let expectation_dependencies = self.expectation.dependencies();
@@ -68,30 +69,49 @@ impl To {
 
         let whens = &runtime.whens;
 
-        let token_stream = quote_spanned! { identifier.span() =>
-            #before_subject
+        // Panics can't be caught in async tests.
+        let token_stream = if runtime.mode.unwrap_or(Mode::Test).is_async() {
+            quote_spanned! { identifier.span() =>
+                #before_subject
 
-            #[allow(unused_variables)]
-            #subject_tokens
+                #[allow(unused_variables)]
+                #subject_tokens
 
-            let expectation_result = #expectation;
+                let expectation_result = #expectation;
 
-            ExecutedTestCase::new(#subject_label.to_string(), vec![#(#whens),*], expectation_result)
+                ExecutedTestCase::new(#subject_label.to_string(), vec![#(#whens),*], expectation_result)
+            }
+        } else {
+            quote_spanned! { identifier.span() =>
+                #before_subject
+
+                #[allow(unused_variables, unused_mut, clippy::redundant_closure)]
+                let subject = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| { #subject_tokens subject }));
+
+                match subject {
+                    #[allow(unused_variables, unused_mut)]
+                    Ok(#mutable_token subject) => match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| #expectation)) {
+                        Ok(expectation_result) => ExecutedTestCase::new(#subject_label.to_string(), vec![#(#whens),*], expectation_result),
+                        Err(panic) => ExecutedTestCase::panicked(#subject_label.to_string(), vec![#(#whens),*], vec![lets_expect::__private::TestPanic::new(lets_expect::__private::Phase::Assertion, panic)]),
+                    },
+                    Err(panic) => ExecutedTestCase::panicked(#subject_label.to_string(), vec![#(#whens),*], vec![lets_expect::__private::TestPanic::new(lets_expect::__private::Phase::Subject, panic)]),
+                }
+            }
         };
 
         (token_stream, dependencies)
     }
 
-    fn subject_tokens(
-        &self,
-        subject: &(bool, syn::Expr),
-        identifier: &Ident,
-        is_panic: bool,
-    ) -> TokenStream {
+    fn subject_tokens(&self, subject: &(bool, syn::Expr), identifier: &Ident) -> TokenStream {
         let mutable_token = mutable_token(subject.0, &subject.1.span());
         let subject = &subject.1;
 
-        if is_panic {
+        if self.expectation.expects_panic() {
+            quote_spanned! { identifier.span() =>
+                #[allow(clippy::no_effect)]
+                let subject = lets_expect::__private::catch_expected_panic(|| { #subject; });
+            }
+        } else if self.expectation.is_panic() {
             quote_spanned! { identifier.span() =>
                 #[allow(clippy::no_effect)]
                 let subject = std::panic::catch_unwind(|| { #subject; });
@@ -2,17 +2,22 @@ use std::collections::HashSet;
 
 use super::{keyword, runtime::Runtime, to::To};
 use proc_macro2::{Ident, TokenStream};
+use syn::Attribute;
 
 pub struct ToBlock {
     pub _keyword: keyword::to,
     pub to: To,
+    pub attributes: Vec<Attribute>,
+    pub pending: bool,
 }
 
 impl ToBlock {
-    pub fn new(keyword: keyword::to, to: To) -> Self {
+    pub fn new(keyword: keyword::to, to: To, attributes: Vec<Attribute>) -> Self {
         Self {
             _keyword: keyword,
             to,
+            attributes,
+            pending: false,
         }
     }
 
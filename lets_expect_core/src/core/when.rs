@@ -1,19 +1,31 @@
 use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::parse_quote;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::{Brace, Comma, Paren};
 use syn::{braced, parenthesized, parse::Parse};
 
-use crate::utils::to_ident::local_to_ident;
+use std::collections::HashSet;
+use std::env;
+use std::path::Path;
 
+use crate::utils::to_ident::{local_to_ident, pat_to_ident};
+
+use super::attributes::{module_attributes, test_attributes};
 use super::context::Context;
 use super::create_module::create_module;
+use super::data_file::{read_rows, Row};
 use super::keyword;
 use super::runtime::Runtime;
-use syn::{Attribute, Expr, Local, Pat, Type};
+use syn::{
+    Attribute, Expr, ExprCall, ExprLit, ExprUnary, Lit, LitInt, Local, Pat, RangeLimits, Type, UnOp,
+};
 use syn::{PatType, Token};
 
 const WHEN_IDENT_PREFIX: &str = "when_";
+/// Every value of a range becomes a module, larger ranges are better covered by a generator.
+const MAX_RANGE_VALUES: i64 = 1000;
 
 struct WhenLet {
     pub attrs: Vec<Attribute>,
@@ -21,7 +33,58 @@ struct WhenLet {
     pub init: (Token![=], Box<Expr>),
 }
 
-impl Parse for WhenLet {
+impl WhenLet {
+    pub fn to_local(&self) -> Local {
+        Local {
+            attrs: self.attrs.clone(),
+            let_token: Default::default(),
+            pat: self.pat.clone(),
+            init: Some(self.init.clone()),
+            semi_token: Default::default(),
+        }
+    }
+}
+
+/// `pat in [a, b, c]` or `pat in 0..4`, a variable taking each of the values in turn.
+struct WhenIn {
+    pub attrs: Vec<Attribute>,
+    pub pat: Pat,
+    pub values: Vec<Expr>,
+}
+
+impl WhenIn {
+    pub fn to_locals(&self) -> Vec<Local> {
+        self.values
+            .iter()
+            .map(|value| {
+                WhenLet {
+                    attrs: self.attrs.clone(),
+                    pat: self.pat.clone(),
+                    init: (Default::default(), Box::new(value.clone())),
+                }
+                .to_local()
+            })
+            .collect()
+    }
+}
+
+enum WhenItem {
+    Let(WhenLet),
+    In(WhenIn),
+    /// `pat in generator()`, a variable taking random values in a property-based test.
+    Generator(WhenLet),
+}
+
+impl WhenItem {
+    fn to_local(&self) -> Option<Local> {
+        match self {
+            Self::Let(when_let) | Self::Generator(when_let) => Some(when_let.to_local()),
+            Self::In(_) => None,
+        }
+    }
+}
+
+impl Parse for WhenItem {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let attrs = input.call(Attribute::parse_outer)?;
         let mut pat = input.parse()?;
@@ -37,53 +100,167 @@ impl Parse for WhenLet {
             });
         }
 
-        let init = (input.parse()?, input.parse()?);
-        Ok(Self { attrs, pat, init })
+        if input.peek(Token![in]) {
+            input.parse::<Token![in]>()?;
+            match input.parse()? {
+                expr @ (Expr::Array(_) | Expr::Range(_)) => {
+                    let values = expand_values(expr)?;
+                    Ok(Self::In(WhenIn { attrs, pat, values }))
+                }
+                expr => Ok(Self::Generator(WhenLet {
+                    attrs,
+                    pat,
+                    init: (Default::default(), Box::new(expr)),
+                })),
+            }
+        } else {
+            let init = (input.parse()?, input.parse()?);
+            Ok(Self::Let(WhenLet { attrs, pat, init }))
+        }
     }
 }
 
-impl WhenLet {
-    pub fn to_local(&self) -> Local {
-        Local {
-            attrs: self.attrs.clone(),
-            let_token: Default::default(),
-            pat: self.pat.clone(),
-            init: Some(self.init.clone()),
-            semi_token: Default::default(),
+/// Expands an array or a range with integer literal bounds into the list of its values.
+fn expand_values(expr: Expr) -> syn::Result<Vec<Expr>> {
+    match expr {
+        Expr::Array(array) => Ok(array.elems.into_iter().collect()),
+        Expr::Range(range) => {
+            let (from, suffix) = match range.from.as_deref() {
+                Some(from) => integer_literal(from)?,
+                None => {
+                    return Err(syn::Error::new(
+                        range.span(),
+                        "Expected a range with a start",
+                    ))
+                }
+            };
+            let to = match range.to.as_deref() {
+                Some(to) => integer_literal(to)?.0,
+                None => {
+                    return Err(syn::Error::new(
+                        range.span(),
+                        "Expected a range with an end",
+                    ))
+                }
+            };
+            let to = match range.limits {
+                RangeLimits::HalfOpen(_) => to,
+                RangeLimits::Closed(_) => to + 1,
+            };
+
+            if to - from > MAX_RANGE_VALUES {
+                return Err(syn::Error::new(
+                    range.span(),
+                    format!(
+                        "Ranges in `when` are limited to {} values, use a generator like `between(a, b)` for larger ones",
+                        MAX_RANGE_VALUES
+                    ),
+                ));
+            }
+
+            Ok((from..to)
+                .map(|value| {
+                    // The generated literals don't appear in the source, pointing them at the
+                    // range would make lints read the range as the literal's text.
+                    let literal = LitInt::new(
+                        &format!("{}{}", value.unsigned_abs(), suffix),
+                        Span::call_site(),
+                    );
+                    if value < 0 {
+                        parse_quote! { -#literal }
+                    } else {
+                        parse_quote! { #literal }
+                    }
+                })
+                .collect())
         }
+        expr => Err(syn::Error::new(
+            expr.span(),
+            "Expected an array, e.g. `[1, 2, 3]`, or a range of integers, e.g. `0..4`",
+        )),
     }
 }
 
-pub struct When {
-    context: Context,
+fn integer_literal(expr: &Expr) -> syn::Result<(i64, String)> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(literal),
+            ..
+        }) => Ok((literal.base10_parse()?, literal.suffix().to_string())),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => integer_literal(expr).map(|(value, suffix)| (-value, suffix)),
+        _ => Err(syn::Error::new(
+            expr.span(),
+            "Expected an integer literal as a range bound",
+        )),
+    }
+}
+
+struct WhenCase {
     identifier: Ident,
     string: String,
     lets: Vec<Local>,
+    generators: Vec<Local>,
+    nested: Vec<Self>,
+}
+
+impl WhenCase {
+    fn new(identifier: Ident, lets: Vec<Local>, nested: Vec<Self>) -> Self {
+        Self {
+            identifier,
+            string: lets_string(&lets),
+            lets,
+            generators: Vec::new(),
+            nested,
+        }
+    }
+}
+
+pub struct When {
+    context: Context,
+    cases: Vec<WhenCase>,
+    /// Absolute path of the file the rows of `when each` were read from.
+    data_file: Option<String>,
 }
 
 impl Parse for When {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let (lets, identifier, string) = if input.peek(Paren) {
-            parse_lets_in_parentheses(input)?
+        let mut data_file = None;
+        let mut cases = if peek_each(input) {
+            let (cases, file) = parse_each(input)?;
+            data_file = file;
+            cases
+        } else if input.peek(Paren) {
+            parse_parentheses(input)?
         } else {
             let identifier = input.parse::<Ident>()?;
-            (
-                Vec::new(),
-                Ident::new(
+            vec![WhenCase {
+                string: identifier.to_string(),
+                identifier: Ident::new(
                     &format!("{}{}", WHEN_IDENT_PREFIX, identifier),
                     identifier.span(),
                 ),
-                identifier.to_string(),
-            )
+                lets: Vec::new(),
+                generators: Vec::new(),
+                nested: Vec::new(),
+            }]
         };
 
-        let identifier = if input.peek(Token![as]) {
+        if input.peek(Token![as]) {
+            if cases.len() != 1 || !cases[0].nested.is_empty() {
+                return Err(input.error(
+                    "`as` can't be used with a `when` that creates multiple contexts, each of them is named after its values",
+                ));
+            }
+
             input.parse::<Token![as]>()?;
             let ident = input.parse::<Ident>()?;
-            Ident::new(&format!("{}{}", WHEN_IDENT_PREFIX, ident), ident.span())
-        } else {
-            identifier
-        };
+            cases[0].identifier =
+                Ident::new(&format!("{}{}", WHEN_IDENT_PREFIX, ident), ident.span());
+        }
 
         let context = if input.peek(Brace) {
             let content;
@@ -94,46 +271,362 @@ impl Parse for When {
         };
 
         Ok(Self {
-            lets,
-            identifier,
-            string,
             context,
+            cases,
+            data_file,
         })
     }
 }
 
-fn parse_lets_in_parentheses(
-    input: &syn::parse::ParseBuffer,
-) -> Result<(Vec<Local>, Ident, String), syn::Error> {
+/// Parses `(a = 1, b = 2)` into a single case or, if any of the variables takes multiple values
+/// with `in`, into nested cases covering every combination of the values. Variables bound to a
+/// generator, like `a in any()`, make the case property-based.
+fn parse_parentheses(input: &syn::parse::ParseBuffer) -> Result<Vec<WhenCase>, syn::Error> {
     let content;
     parenthesized!(content in input);
 
     let string = content.to_string();
-    let when_lets: Punctuated<WhenLet, Comma> = Punctuated::parse_separated_nonempty(&content)?;
-    let lets: Vec<Local> = when_lets.iter().map(WhenLet::to_local).collect();
+    let items: Punctuated<WhenItem, Comma> = Punctuated::parse_separated_nonempty(&content)?;
 
-    if lets.is_empty() {
+    if items.is_empty() {
         return Err(syn::Error::new(
             Span::call_site(),
             "Expected at least one assignment",
         ));
     }
 
-    let name = WHEN_IDENT_PREFIX.to_string()
+    if items.iter().all(|item| !matches!(item, WhenItem::In(_))) {
+        let locals: Vec<Local> = items.iter().filter_map(WhenItem::to_local).collect();
+        let identifier = Ident::new(&lets_identifier(&locals), input.span());
+
+        return Ok(vec![WhenCase {
+            identifier,
+            string,
+            lets: items
+                .iter()
+                .filter(|item| matches!(item, WhenItem::Let(_)))
+                .filter_map(WhenItem::to_local)
+                .collect(),
+            generators: items
+                .iter()
+                .filter(|item| matches!(item, WhenItem::Generator(_)))
+                .filter_map(WhenItem::to_local)
+                .collect(),
+            nested: Vec::new(),
+        }]);
+    }
+
+    if let Some(generator) = items.iter().find_map(|item| match item {
+        WhenItem::Generator(when_let) => Some(&when_let.init.1),
+        _ => None,
+    }) {
+        return Err(syn::Error::new(
+            generator.span(),
+            "Generators can't be combined with lists of values in the same `when`",
+        ));
+    }
+
+    let dimensions = items
+        .iter()
+        .filter_map(|item| match item {
+            WhenItem::Let(when_let) => Some(vec![when_let.to_local()]),
+            WhenItem::In(when_in) => Some(when_in.to_locals()),
+            WhenItem::Generator(_) => None,
+        })
+        .collect::<Vec<Vec<Local>>>();
+
+    Ok(cartesian_product(&dimensions))
+}
+
+fn cartesian_product(dimensions: &[Vec<Local>]) -> Vec<WhenCase> {
+    match dimensions.split_first() {
+        Some((values, rest)) => {
+            let names = unique_identifiers(
+                values
+                    .iter()
+                    .map(|value| lets_identifier(std::slice::from_ref(value)))
+                    .collect(),
+            );
+
+            values
+                .iter()
+                .zip(names)
+                .map(|(value, name)| {
+                    WhenCase::new(
+                        Ident::new(&name, value.span()),
+                        vec![value.clone()],
+                        cartesian_product(rest),
+                    )
+                })
+                .collect()
+        }
+        None => Vec::new(),
+    }
+}
+
+fn lets_identifier(lets: &[Local]) -> String {
+    WHEN_IDENT_PREFIX.to_string()
         + lets
             .iter()
             .map(local_to_ident)
             .collect::<Vec<String>>()
             .join("_")
-            .as_str();
-    let identifier = Ident::new(name.as_str(), input.span());
-    Ok((lets, identifier, string))
+            .as_str()
+}
+
+fn lets_string(lets: &[Local]) -> String {
+    lets.iter()
+        .map(|local| {
+            let pat = &local.pat;
+            let expr = &local.init.as_ref().unwrap().1;
+            quote! { #pat = #expr }.to_string()
+        })
+        .collect::<Vec<String>>()
+        .join(" , ")
+}
+
+/// Values without a readable name, like two different strings, would end up in modules with
+/// the same name. Repeated names get the first numbered suffix that no other name has.
+fn unique_identifiers(names: Vec<String>) -> Vec<String> {
+    let mut taken = names.iter().cloned().collect::<HashSet<String>>();
+    let mut seen = HashSet::new();
+
+    names
+        .into_iter()
+        .map(|name| {
+            if seen.insert(name.clone()) {
+                return name;
+            }
+
+            let unique = (2..)
+                .map(|number| format!("{}_{}", name, number))
+                .find(|candidate| !taken.contains(candidate))
+                .unwrap();
+            taken.insert(unique.clone());
+            unique
+        })
+        .collect()
+}
+
+fn peek_each(input: &syn::parse::ParseBuffer) -> bool {
+    let fork = input.fork();
+
+    fork.parse::<keyword::each>().is_ok() && fork.parse::<Pat>().is_ok() && fork.peek(Token![in])
+}
+
+/// Parses `each (a, b) in [(1, 2), (3, 4)]` or `each row in file("cases.json")` into one case
+/// per row. Rows read from a file are returned along with the file's absolute path.
+fn parse_each(
+    input: &syn::parse::ParseBuffer,
+) -> Result<(Vec<WhenCase>, Option<String>), syn::Error> {
+    input.parse::<keyword::each>()?;
+    let pat = input.parse::<Pat>()?;
+    input.parse::<Token![in]>()?;
+    let rows = match Expr::parse_without_eager_brace(input)? {
+        Expr::Array(array) => array.elems,
+        Expr::Call(call) if is_file_call(&call) => return parse_file(&pat, &call),
+        expr => {
+            return Err(syn::Error::new(
+                expr.span(),
+                "Expected an array of rows, e.g. `[(1, 2), (3, 4)]`, or a data file, e.g. `file(\"tests/data/cases.json\")`",
+            ))
+        }
+    };
+
+    let lets = rows
+        .iter()
+        .map(|row| row_lets(&pat, row))
+        .collect::<Result<Vec<Vec<Local>>, syn::Error>>()?;
+    let names = unique_identifiers(lets.iter().map(|lets| lets_identifier(lets)).collect());
+
+    let cases = rows
+        .iter()
+        .zip(lets)
+        .zip(names)
+        .map(|((row, lets), name)| WhenCase::new(Ident::new(&name, row.span()), lets, Vec::new()))
+        .collect();
+
+    Ok((cases, None))
+}
+
+fn is_file_call(call: &ExprCall) -> bool {
+    matches!(&*call.func, Expr::Path(path) if path.path.is_ident("file"))
+}
+
+/// Reads the rows of a JSON or CSV file, relative to the directory of the crate being tested.
+/// A pattern with a single identifier binds all the fields of a row, a tuple only the named ones.
+fn parse_file(pat: &Pat, call: &ExprCall) -> Result<(Vec<WhenCase>, Option<String>), syn::Error> {
+    let path = match call.args.first() {
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Str(path),
+            ..
+        })) if call.args.len() == 1 => path,
+        _ => {
+            return Err(syn::Error::new(
+                call.span(),
+                "Expected a path to a data file, e.g. `file(\"tests/data/cases.json\")`",
+            ))
+        }
+    };
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let full_path = Path::new(&manifest_dir).join(path.value());
+    let rows = read_rows(&full_path).map_err(|error| syn::Error::new(path.span(), error))?;
+
+    let lets = rows
+        .into_iter()
+        .map(|row| file_row_lets(pat, row))
+        .collect::<Result<Vec<Vec<Local>>, syn::Error>>()?;
+    let names = unique_identifiers(lets.iter().map(|lets| lets_identifier(lets)).collect());
+
+    let cases = lets
+        .into_iter()
+        .zip(names)
+        .map(|(lets, name)| WhenCase::new(Ident::new(&name, path.span()), lets, Vec::new()))
+        .collect();
+
+    Ok((cases, Some(full_path.display().to_string())))
+}
+
+fn file_row_lets(pat: &Pat, row: Row) -> Result<Vec<Local>, syn::Error> {
+    let local = |pat: Pat, value: Expr| {
+        WhenLet {
+            attrs: Vec::new(),
+            pat,
+            init: (Default::default(), Box::new(value)),
+        }
+        .to_local()
+    };
+
+    match pat {
+        Pat::Ident(_) => Ok(row
+            .into_iter()
+            .map(|(name, value)| {
+                let ident = Ident::new(&name, pat.span());
+                local(parse_quote! { #ident }, value)
+            })
+            .collect()),
+        Pat::Tuple(pats) => pats
+            .elems
+            .iter()
+            .map(|pat| {
+                let name = pat_to_ident(pat).to_string();
+                match row.iter().find(|(field, _)| *field == name) {
+                    Some((_, value)) => Ok(local(pat.clone(), value.clone())),
+                    None => Err(syn::Error::new(
+                        pat.span(),
+                        format!("The data file has no field named `{}`", name),
+                    )),
+                }
+            })
+            .collect(),
+        _ => Err(syn::Error::new(
+            pat.span(),
+            "Expected an identifier or a tuple of identifiers",
+        )),
+    }
+}
+
+fn row_lets(pat: &Pat, row: &Expr) -> Result<Vec<Local>, syn::Error> {
+    match (pat, row) {
+        (Pat::Tuple(pats), Expr::Tuple(values)) if pats.elems.len() == values.elems.len() => pats
+            .elems
+            .iter()
+            .zip(values.elems.iter())
+            .map(|(pat, value)| row_lets(pat, value))
+            .collect::<Result<Vec<Vec<Local>>, syn::Error>>()
+            .map(|lets| lets.into_iter().flatten().collect()),
+        (Pat::Tuple(pats), _) => Err(syn::Error::new(
+            row.span(),
+            format!("Expected a tuple with {} values", pats.elems.len()),
+        )),
+        (Pat::Ident(_) | Pat::Type(_), _) => Ok(vec![WhenLet {
+            attrs: Vec::new(),
+            pat: pat.clone(),
+            init: (Default::default(), Box::new(row.clone())),
+        }
+        .to_local()]),
+        _ => Err(syn::Error::new(
+            pat.span(),
+            "Expected an identifier or a tuple of identifiers",
+        )),
+    }
 }
 
 impl When {
-    pub fn to_tokens(&self, keyword: &keyword::when, runtime: &Runtime) -> TokenStream {
-        let runtime = runtime.add_when(self.string.clone()).add_lets(&self.lets);
-        let context = self.context.to_tokens(&keyword.span(), &runtime);
-        create_module(&keyword.span(), &self.identifier, &context)
+    pub fn to_tokens(
+        &self,
+        keyword: &keyword::when,
+        attributes: &[Attribute],
+        runtime: &Runtime,
+    ) -> TokenStream {
+        let runtime = runtime.add_attributes(&test_attributes(attributes));
+        let module_attributes = module_attributes(attributes);
+        let cases: TokenStream = self
+            .cases
+            .iter()
+            .map(|case| self.case_tokens(case, keyword, &module_attributes, &runtime))
+            .collect();
+
+        // Makes cargo rebuild the tests when the data file changes.
+        let data_file = self.data_file.iter();
+
+        quote! {
+            #(const _: &[u8] = include_bytes!(#data_file);)*
+            #cases
+        }
+    }
+
+    fn case_tokens(
+        &self,
+        case: &WhenCase,
+        keyword: &keyword::when,
+        attributes: &[Attribute],
+        runtime: &Runtime,
+    ) -> TokenStream {
+        let runtime = runtime
+            .add_when(case.string.clone())
+            .add_generators(&case.generators)
+            .add_lets(&case.lets);
+        let content = if case.nested.is_empty() {
+            self.context.to_tokens(&keyword.span(), &runtime)
+        } else {
+            case.nested
+                .iter()
+                .map(|nested| self.case_tokens(nested, keyword, &[], &runtime))
+                .collect()
+        };
+
+        create_module(&keyword.span(), &case.identifier, attributes, &content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_malformed_data_file_is_a_compile_error() {
+        let path = env::temp_dir().join(format!("lets_expect_{}_when.json", std::process::id()));
+        fs::write(&path, "[{ \"a\": }]").unwrap();
+        let path = path.display().to_string();
+
+        let error = syn::parse2::<When>(quote! {
+            each row in file(#path) {
+                to equal(a)
+            }
+        })
+        .err()
+        .expect("Expected a malformed data file to fail the parsing");
+
+        let message = format!(
+            "Malformed data file {}: expected value at line 1 column 9",
+            path
+        );
+        assert_eq!(
+            error.to_compile_error().to_string(),
+            quote! { compile_error! { #message } }.to_string()
+        );
     }
 }
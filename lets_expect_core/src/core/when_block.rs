@@ -1,17 +1,24 @@
 use super::{keyword, runtime::Runtime, when::When};
 use proc_macro2::TokenStream;
+use syn::Attribute;
 
 pub struct WhenBlock {
     keyword: keyword::when,
     when: When,
+    attributes: Vec<Attribute>,
 }
 
 impl WhenBlock {
-    pub fn new(keyword: keyword::when, when: When) -> Self {
-        Self { keyword, when }
+    pub fn new(keyword: keyword::when, when: When, attributes: Vec<Attribute>) -> Self {
+        Self {
+            keyword,
+            when,
+            attributes,
+        }
     }
 
     pub fn to_tokens(&self, runtime: &Runtime) -> TokenStream {
-        self.when.to_tokens(&self.keyword, runtime)
+        self.when
+            .to_tokens(&self.keyword, &self.attributes, runtime)
     }
 }
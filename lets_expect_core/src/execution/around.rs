@@ -0,0 +1,4 @@
+/// Runs `run` inside of an `around` hook, which gets a function it has to call to run the test.
+pub fn run_around(hook: impl FnOnce(&mut dyn FnMut()), mut run: impl FnMut()) {
+    hook(&mut run);
+}
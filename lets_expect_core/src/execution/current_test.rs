@@ -0,0 +1,92 @@
+use std::{cell::RefCell, path::PathBuf};
+
+thread_local! {
+    static CURRENT_TEST: RefCell<Option<TestLocation>> = const { RefCell::new(None) };
+}
+
+struct TestLocation {
+    manifest_dir: PathBuf,
+    module_path: String,
+    name: String,
+    snapshots: usize,
+    property: bool,
+}
+
+/// Records which generated test is running on the current thread for as long as it is alive.
+/// Assertions that need to know the test they are part of, like `match_snapshot`, read it.
+pub struct CurrentTest {
+    previous: Option<TestLocation>,
+}
+
+impl CurrentTest {
+    pub fn enter(manifest_dir: &str, module_path: &str, name: &str) -> Self {
+        Self::enter_location(TestLocation {
+            manifest_dir: PathBuf::from(manifest_dir),
+            module_path: module_path.to_string(),
+            name: name.to_string(),
+            snapshots: 0,
+            property: false,
+        })
+    }
+
+    /// Like `enter`, for a test generated for a property-based `when`. Its cases get different
+    /// values every run, so they can't be compared with snapshots.
+    pub fn enter_property(manifest_dir: &str, module_path: &str, name: &str) -> Self {
+        Self::enter_location(TestLocation {
+            manifest_dir: PathBuf::from(manifest_dir),
+            module_path: module_path.to_string(),
+            name: name.to_string(),
+            snapshots: 0,
+            property: true,
+        })
+    }
+
+    fn enter_location(location: TestLocation) -> Self {
+        Self {
+            previous: CURRENT_TEST.with(|current| current.replace(Some(location))),
+        }
+    }
+
+    /// Path of the next snapshot file of the running test. Fails outside of a generated test and
+    /// in property-based tests.
+    ///
+    /// Snapshots live in the `snapshots` directory of the crate and are named after the module
+    /// path of the test, e.g. `snapshots/crate__tests__expect_a__when_b__to_c.snap`. Every
+    /// subsequent snapshot taken by the same test gets a numbered suffix.
+    pub fn next_snapshot_path() -> Result<PathBuf, String> {
+        CURRENT_TEST.with(|current| match current.borrow_mut().as_mut() {
+            None => Err("match_snapshot can only be used in tests generated by lets_expect!".to_string()),
+            Some(test) if test.property => Err(
+                "match_snapshot can't be used in a property-based when, its values change every run"
+                    .to_string(),
+            ),
+            Some(test) => {
+                test.snapshots += 1;
+
+                let suffix = if test.snapshots > 1 {
+                    format!("_{}", test.snapshots)
+                } else {
+                    String::new()
+                };
+                let file_name = format!(
+                    "{}__{}{}",
+                    test.module_path.replace("::", "__"),
+                    test.name,
+                    suffix
+                );
+
+                Ok(test
+                    .manifest_dir
+                    .join("snapshots")
+                    .join(format!("{}.snap", file_name)))
+            }
+        })
+    }
+}
+
+impl Drop for CurrentTest {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_TEST.with(|current| *current.borrow_mut() = previous);
+    }
+}
@@ -6,8 +6,8 @@ use super::executed_assertion::ExecutedAssertion;
 
 pub enum ExecutedExpectation {
     Single(ExecutedAssertion),
-    Group(String, String, Box<ExecutedExpectation>),
-    Many(Vec<ExecutedExpectation>),
+    Group(String, String, Box<Self>),
+    Many(Vec<Self>),
 }
 
 impl ExecutedExpectation {
@@ -4,12 +4,14 @@ use colored::Colorize;
 
 use crate::utils::indent::indent;
 
-use super::executed_expectation::ExecutedExpectation;
+use super::{executed_expectation::ExecutedExpectation, test_panic::TestPanic};
 
 pub struct ExecutedTestCase {
     subject: String,
     whens: Vec<String>,
     expectation: ExecutedExpectation,
+    skip_reason: Option<String>,
+    panics: Vec<TestPanic>,
 }
 
 impl ExecutedTestCase {
@@ -18,11 +20,33 @@ impl ExecutedTestCase {
             subject,
             whens: whens.iter().map(|when| when.to_string()).collect(),
             expectation,
+            skip_reason: None,
+            panics: Vec::new(),
+        }
+    }
+
+    /// A test case that didn't run because of a `skip_if`.
+    pub fn skipped(subject: String, whens: Vec<&str>, reason: &str) -> Self {
+        Self {
+            skip_reason: Some(reason.to_string()),
+            ..Self::new(subject, whens, ExecutedExpectation::Many(Vec::new()))
+        }
+    }
+
+    /// A test case that panicked before its expectations were verified, or in an `after` block.
+    pub fn panicked(subject: String, whens: Vec<&str>, panics: Vec<TestPanic>) -> Self {
+        Self {
+            panics,
+            ..Self::new(subject, whens, ExecutedExpectation::Many(Vec::new()))
         }
     }
 
     pub fn failed(&self) -> bool {
-        self.expectation.failed()
+        self.expectation.failed() || !self.panics.is_empty()
+    }
+
+    pub fn skip_reason(&self) -> Option<&str> {
+        self.skip_reason.as_deref()
     }
 }
 
@@ -49,7 +73,14 @@ impl Display for ExecutedTestCase {
             whens.push('\n');
         }
 
-        let expectations = self.expectation.pretty_print();
+        let expectations = match &self.skip_reason {
+            Some(reason) => vec![format!("{} {}", "Skipped:".yellow().bold(), reason)],
+            None => {
+                let mut expectations = self.expectation.pretty_print();
+                expectations.extend(self.panics.iter().map(TestPanic::to_string));
+                expectations
+            }
+        };
         let expectations = indent(&expectations, (self.whens.len() + 1) as u8);
 
         write!(
@@ -0,0 +1,95 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, UnwindSafe},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+type Hook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+thread_local! {
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// The hook that was installed before the first capture, and how many captures are running.
+struct ScopedHook {
+    captures: usize,
+    previous: Option<Arc<Hook>>,
+}
+
+static SCOPED_HOOK: Mutex<ScopedHook> = Mutex::new(ScopedHook {
+    captures: 0,
+    previous: None,
+});
+
+fn scoped_hook() -> MutexGuard<'static, ScopedHook> {
+    SCOPED_HOOK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Installs the silencing hook when the first capture starts. The hook is global, so while any
+/// thread is capturing it only stays quiet on threads that are currently inside
+/// `catch_expected_panic` and defers to the previous hook everywhere else.
+fn install_hook() {
+    let mut state = scoped_hook();
+
+    if state.captures == 0 {
+        let previous = Arc::new(panic::take_hook());
+        let delegate = Arc::clone(&previous);
+
+        panic::set_hook(Box::new(move |info| {
+            if CAPTURING.with(Cell::get) {
+                let location = info.location().map(ToString::to_string);
+                LOCATION.with(|captured| *captured.borrow_mut() = location);
+            } else {
+                delegate(info);
+            }
+        }));
+        state.previous = Some(previous);
+    }
+
+    state.captures += 1;
+}
+
+/// Restores the previous hook once the last running capture is done.
+fn restore_hook() {
+    let mut state = scoped_hook();
+    state.captures -= 1;
+
+    if state.captures == 0 {
+        // Dropping the silencing hook releases its handle on the previous one.
+        drop(panic::take_hook());
+
+        if let Some(previous) = state.previous.take() {
+            match Arc::try_unwrap(previous) {
+                Ok(previous) => panic::set_hook(previous),
+                Err(previous) => panic::set_hook(Box::new(move |info| previous(info))),
+            }
+        }
+    }
+}
+
+/// Runs a subject that is expected to panic without printing the panic message to stderr.
+/// The previous panic hook is restored afterwards.
+pub fn catch_expected_panic<F>(subject: F) -> Result<(), Box<dyn Any + Send>>
+where
+    F: FnOnce() + UnwindSafe,
+{
+    install_hook();
+    LOCATION.with(|captured| captured.borrow_mut().take());
+
+    let capturing = CAPTURING.with(|capturing| capturing.replace(true));
+    let result = panic::catch_unwind(subject);
+    CAPTURING.with(|current| current.set(capturing));
+
+    restore_hook();
+
+    result
+}
+
+/// Location of the last panic silenced by `catch_expected_panic` on this thread.
+pub fn expected_panic_location() -> Option<String> {
+    LOCATION.with(|captured| captured.borrow().clone())
+}
@@ -1,5 +1,11 @@
+pub mod around;
+pub mod current_test;
 pub mod executed_assertion;
 pub mod executed_expectation;
 pub mod executed_test_case;
+pub mod expected_panic;
+pub mod module_hooks;
+pub mod tags;
 pub mod test_failure;
+pub mod test_panic;
 pub mod test_result;
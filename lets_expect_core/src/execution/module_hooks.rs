@@ -0,0 +1,122 @@
+use std::{
+    mem,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex, OnceLock, PoisonError,
+    },
+};
+
+#[cfg(any(unix, windows))]
+use std::sync::Once;
+
+/// The state of the `before_all` and `after_all` hooks of a module, shared by its tests.
+///
+/// Tests running in parallel wait for `before_all` to finish instead of running it again. A
+/// `before_all` that panicked runs again in the next test. `after_all` runs at most once, after
+/// the last test of the module or, if some of the tests don't run, when the process exits.
+pub struct ModuleHooks {
+    before_all: OnceLock<()>,
+    after_all_ran: AtomicBool,
+    finished_tests: AtomicUsize,
+    after_all: fn(),
+}
+
+/// Modules whose `before_all` ran, their `after_all` runs when the process exits at the latest.
+static STARTED_MODULES: Mutex<Vec<&'static ModuleHooks>> = Mutex::new(Vec::new());
+#[cfg(any(unix, windows))]
+static AT_EXIT: Once = Once::new();
+
+#[cfg(any(unix, windows))]
+extern "C" {
+    fn atexit(callback: extern "C" fn()) -> std::os::raw::c_int;
+}
+
+impl ModuleHooks {
+    pub const fn new(after_all: fn()) -> Self {
+        Self {
+            before_all: OnceLock::new(),
+            after_all_ran: AtomicBool::new(false),
+            finished_tests: AtomicUsize::new(0),
+            after_all,
+        }
+    }
+
+    pub fn before_all(&'static self, hook: impl FnOnce()) {
+        self.before_all.get_or_init(|| {
+            hook();
+            finish_at_exit(self);
+        });
+    }
+
+    /// Runs `after_all` once all `tests` of the module have finished, unless none of them got
+    /// as far as running `before_all`, e.g. because all of them were skipped.
+    ///
+    /// `tests` is the number of tests the macro generated, when some of them don't run `after_all`
+    /// waits for the process to exit instead.
+    pub fn finish_test(&self, tests: usize) {
+        if self.finished_tests.fetch_add(1, Ordering::SeqCst) + 1 >= tests {
+            self.after_all();
+        }
+    }
+
+    fn after_all(&self) {
+        if self.before_all.get().is_some() && !self.after_all_ran.swap(true, Ordering::SeqCst) {
+            (self.after_all)();
+        }
+    }
+}
+
+/// Makes sure `after_all` runs even if the number of tests the module expects is never reached,
+/// e.g. when some of them are filtered out by `cargo test`.
+fn finish_at_exit(hooks: &'static ModuleHooks) {
+    STARTED_MODULES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(hooks);
+
+    #[cfg(any(unix, windows))]
+    AT_EXIT.call_once(|| {
+        // SAFETY: `atexit` only registers the function, which matches the signature it expects.
+        // The function runs the `after_all` hooks, user code that can panic, but it catches the
+        // panics instead of unwinding into the C runtime.
+        unsafe {
+            atexit(finish_started_modules);
+        }
+    });
+}
+
+/// Runs the `after_all` hooks that haven't run yet, of the nested modules first.
+#[cfg(any(unix, windows))]
+extern "C" fn finish_started_modules() {
+    // Unwinding out of an exit handler aborts the process.
+    let _ = panic::catch_unwind(|| {
+        let modules = mem::take(
+            &mut *STARTED_MODULES
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+
+        for hooks in modules.into_iter().rev() {
+            let _ = panic::catch_unwind(AssertUnwindSafe(|| hooks.after_all()));
+        }
+    });
+}
+
+/// Reports a finished test to the hooks of a module when dropped, even if the test panicked.
+pub struct AfterAllGuard {
+    hooks: &'static ModuleHooks,
+    tests: usize,
+}
+
+impl AfterAllGuard {
+    pub fn new(hooks: &'static ModuleHooks, tests: usize) -> Self {
+        Self { hooks, tests }
+    }
+}
+
+impl Drop for AfterAllGuard {
+    fn drop(&mut self) {
+        self.hooks.finish_test(self.tests);
+    }
+}
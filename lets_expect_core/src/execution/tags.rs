@@ -0,0 +1,51 @@
+use std::env;
+
+/// Comma separated tags, a test runs only if it has at least one of them.
+const TAGS_VARIABLE: &str = "LETS_EXPECT_TAGS";
+/// Comma separated tags, a test with any of them doesn't run.
+const EXCLUDE_TAGS_VARIABLE: &str = "LETS_EXPECT_EXCLUDE_TAGS";
+
+/// The reason a test with `tags` is skipped, given the tags selected in the environment.
+pub fn skipped_by_tags(tags: &[&str]) -> Option<String> {
+    if let Some(excluded) = env_tags(EXCLUDE_TAGS_VARIABLE) {
+        if let Some(tag) = tags
+            .iter()
+            .find(|tag| excluded.iter().any(|excluded| excluded == *tag))
+        {
+            return Some(format!(
+                "tagged with `{}`, excluded by {}",
+                tag, EXCLUDE_TAGS_VARIABLE
+            ));
+        }
+    }
+
+    if let Some(included) = env_tags(TAGS_VARIABLE) {
+        if !tags
+            .iter()
+            .any(|tag| included.iter().any(|included| included == tag))
+        {
+            return Some(format!(
+                "not tagged with any of {}={}",
+                TAGS_VARIABLE,
+                included.join(",")
+            ));
+        }
+    }
+
+    None
+}
+
+fn env_tags(variable: &str) -> Option<Vec<String>> {
+    let tags = env::var(variable)
+        .ok()?
+        .split(',')
+        .map(|tag| tag.trim().to_string())
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<String>>();
+
+    if tags.is_empty() {
+        None
+    } else {
+        Some(tags)
+    }
+}
@@ -1,19 +1,55 @@
 use super::executed_test_case::ExecutedTestCase;
+use crate::property::counterexample::Counterexample;
 use std::fmt::Debug;
 
 pub struct TestFailure {
     test_cases: Vec<ExecutedTestCase>,
+    counterexample: Option<Counterexample>,
+    message: Option<String>,
 }
 
 impl TestFailure {
     pub fn new(test_cases: Vec<ExecutedTestCase>) -> Self {
-        Self { test_cases }
+        Self {
+            test_cases,
+            counterexample: None,
+            message: None,
+        }
+    }
+
+    pub fn with_counterexample(self, counterexample: Counterexample) -> Self {
+        Self {
+            counterexample: Some(counterexample),
+            ..self
+        }
+    }
+
+    pub fn with_message(self, message: String) -> Self {
+        Self {
+            message: Some(message),
+            ..self
+        }
     }
 }
 
 impl Debug for TestFailure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let test_cases = self.test_cases.iter().map(|test_case| test_case.to_string()).collect::<Vec<String>>().join("\n");
-        write!(f, "\n\n{}", test_cases)
+        let test_cases = self
+            .test_cases
+            .iter()
+            .map(|test_case| test_case.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+        let counterexample = self
+            .counterexample
+            .as_ref()
+            .map(|counterexample| format!("{}\n", counterexample))
+            .unwrap_or_default();
+        let message = self
+            .message
+            .as_ref()
+            .map(|message| format!("{}\n", message))
+            .unwrap_or_default();
+        write!(f, "\n\n{}{}{}", message, counterexample, test_cases)
     }
-}
\ No newline at end of file
+}
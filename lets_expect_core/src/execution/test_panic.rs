@@ -0,0 +1,78 @@
+use std::{any::Any, fmt::Display};
+
+use colored::Colorize;
+
+use super::executed_test_case::ExecutedTestCase;
+
+/// The part of a test a panic happened in.
+pub enum Phase {
+    Let(&'static str),
+    Before(usize),
+    Subject,
+    Assertion,
+    Story,
+    After(usize),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Let(name) => write!(f, "let {}", name),
+            Self::Before(number) => write!(f, "before block {}", number),
+            Self::Subject => write!(f, "subject"),
+            Self::Assertion => write!(f, "assertion"),
+            Self::Story => write!(f, "story"),
+            Self::After(number) => write!(f, "after block {}", number),
+        }
+    }
+}
+
+/// A panic caught while running a test. It fails the test like a failed assertion would.
+pub struct TestPanic {
+    phase: Phase,
+    message: String,
+}
+
+impl TestPanic {
+    pub fn new(phase: Phase, payload: Box<dyn Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast_ref::<&str>() {
+                Some(message) => message.to_string(),
+                None => "a non-string payload".to_string(),
+            },
+        };
+
+        Self { phase, message }
+    }
+}
+
+impl Display for TestPanic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}: {}",
+            "Panicked in".red(),
+            self.phase.to_string().yellow().bold(),
+            format!("{:?}", self.message).red().bold()
+        )
+    }
+}
+
+/// The test cases of a test followed by a test case reporting its panics, if there were any.
+pub fn test_cases_with_panics(
+    subject: &str,
+    whens: Vec<&str>,
+    mut test_cases: Vec<ExecutedTestCase>,
+    panics: Vec<TestPanic>,
+) -> Vec<ExecutedTestCase> {
+    if !panics.is_empty() {
+        test_cases.push(ExecutedTestCase::panicked(
+            subject.to_string(),
+            whens,
+            panics,
+        ));
+    }
+
+    test_cases
+}
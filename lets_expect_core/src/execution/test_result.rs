@@ -1,11 +1,47 @@
-use super::{test_failure::TestFailure, executed_test_case::ExecutedTestCase};
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    thread,
+};
+
+use colored::Colorize;
+
+use super::{executed_test_case::ExecutedTestCase, test_failure::TestFailure};
 
 pub type TestResult = Result<(), TestFailure>;
 
 pub fn test_result_from_cases(test_cases: Vec<ExecutedTestCase>) -> TestResult {
+    for test_case in test_cases
+        .iter()
+        .filter(|test_case| test_case.skip_reason().is_some())
+    {
+        report(test_case);
+    }
+
     if test_cases.iter().any(|test_case| test_case.failed()) {
         Err(TestFailure::new(test_cases))
     } else {
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// A pending test is expected to fail, either with failed expectations or with a panic.
+/// Once it passes it's no longer pending and it fails itself, so that `pending` gets removed.
+pub fn pending_test_result(name: &str, result: thread::Result<TestResult>) -> TestResult {
+    match result {
+        Ok(Ok(())) => {
+            Err(TestFailure::new(Vec::new())
+                .with_message(format!("{} passes, remove pending", name)))
+        }
+        Ok(Err(_)) | Err(_) => {
+            report(format!("{} {}", "PENDING".yellow().bold(), name));
+            Ok(())
+        }
+    }
+}
+
+/// The test harness captures `println!` and `eprintln!` and only shows the output of failed tests.
+/// Writing to stderr directly shows which passing tests are pending or skipped, and why.
+fn report(line: impl Display) {
+    let _ = writeln!(io::stderr(), "{}", line);
+}
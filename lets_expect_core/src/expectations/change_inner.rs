@@ -9,6 +9,7 @@ use super::{
     many::ManyExpectation,
 };
 
+#[allow(clippy::large_enum_variant)]
 pub enum ChangeInnerExpectation {
     Expression(ChangeExpressionExpectation),
     Many(ChangeManyExpectation),
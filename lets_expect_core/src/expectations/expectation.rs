@@ -5,12 +5,15 @@ use syn::parse::{Parse, ParseStream};
 
 use super::{
     expectation_tokens::ExpectationTokens, expectation_type::ExpectationType,
-    not_panic::NotPanicExpectation, panic::PanicExpectation, return_value::ReturnValueExpectation,
+    not_panic::NotPanicExpectation, panic::PanicExpectation, panic_with::PanicWithExpectation,
+    return_value::ReturnValueExpectation,
 };
 
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum Expectation {
     Result(ReturnValueExpectation),
     Panic(PanicExpectation),
+    PanicWith(PanicWithExpectation),
     NotPanic(NotPanicExpectation),
 }
 
@@ -18,6 +21,8 @@ impl Parse for Expectation {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if PanicExpectation::peek(&input) {
             Ok(Self::Panic(input.parse::<PanicExpectation>()?))
+        } else if PanicWithExpectation::peek(&input) {
+            Ok(Self::PanicWith(input.parse::<PanicWithExpectation>()?))
         } else if NotPanicExpectation::peek(&input) {
             Ok(Self::NotPanic(input.parse::<NotPanicExpectation>()?))
         } else {
@@ -30,6 +35,7 @@ impl ExpectationType for Expectation {
     fn span(&self) -> proc_macro2::Span {
         match self {
             Self::Panic(expectation) => expectation.span(),
+            Self::PanicWith(expectation) => expectation.span(),
             Self::NotPanic(expectation) => expectation.span(),
             Self::Result(expectation) => expectation.span(),
         }
@@ -38,6 +44,7 @@ impl ExpectationType for Expectation {
     fn identifier_string(&self) -> &str {
         match self {
             Self::Panic(expectation) => expectation.identifier_string(),
+            Self::PanicWith(expectation) => expectation.identifier_string(),
             Self::NotPanic(expectation) => expectation.identifier_string(),
             Self::Result(expectation) => expectation.identifier_string(),
         }
@@ -46,6 +53,7 @@ impl ExpectationType for Expectation {
     fn dependencies(&self) -> HashSet<Ident> {
         match self {
             Self::Panic(expectation) => expectation.dependencies(),
+            Self::PanicWith(expectation) => expectation.dependencies(),
             Self::NotPanic(expectation) => expectation.dependencies(),
             Self::Result(expectation) => expectation.dependencies(),
         }
@@ -59,6 +67,7 @@ impl ExpectationType for Expectation {
     ) -> ExpectationTokens {
         match self {
             Self::Panic(expectation) => expectation.tokens(),
+            Self::PanicWith(expectation) => expectation.tokens(),
             Self::NotPanic(expectation) => expectation.tokens(),
             Self::Result(expectation) => {
                 expectation.tokens(ident_prefix, subject_reference, subject_mutable)
@@ -70,8 +79,15 @@ impl ExpectationType for Expectation {
 impl Expectation {
     pub(crate) fn is_panic(&self) -> bool {
         match self {
-            Self::NotPanic(_) | Self::Panic(_) => true,
+            Self::NotPanic(_) | Self::Panic(_) | Self::PanicWith(_) => true,
             Self::Result(_) => false,
         }
     }
+
+    pub(crate) fn expects_panic(&self) -> bool {
+        match self {
+            Self::Panic(_) | Self::PanicWith(_) => true,
+            Self::NotPanic(_) | Self::Result(_) => false,
+        }
+    }
 }
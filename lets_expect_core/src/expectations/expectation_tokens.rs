@@ -46,7 +46,7 @@ impl GroupAssertionTokens {
 pub enum AssertionTokens {
     Single(SingleAssertionTokens),
     Group(GroupAssertionTokens),
-    Many(Vec<AssertionTokens>),
+    Many(Vec<Self>),
 }
 
 #[derive(Clone)]
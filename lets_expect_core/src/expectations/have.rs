@@ -111,7 +111,7 @@ impl HaveExpectation {
     pub fn dependencies(&self) -> HashSet<Ident> {
         expr_dependencies(&self.expectation_expression.expr)
             .into_iter()
-            .chain(self.inner.dependencies().into_iter())
+            .chain(self.inner.dependencies())
             .collect()
     }
 }
@@ -7,16 +7,17 @@ use super::{
     be_err_and::BeErrAndExpectation, be_ok_and::BeOkAndExpectation,
     be_some_and::BeSomeAndExpectation, expectation_tokens::ExpectationTokens,
     expectation_type::ExpectationType, expression::ExpressionExpectation, have::HaveExpectation,
-    many::ManyExpectation,
+    many::ManyExpectation, match_pattern_and::MatchPatternAndExpectation,
 };
 
 pub(crate) enum InnerExpectation {
     Expression(ExpressionExpectation),
-    Many(ManyExpectation<InnerExpectation>),
+    Many(ManyExpectation<Self>),
     Have(HaveExpectation),
     BeSomeAnd(BeSomeAndExpectation),
     BeOkAndAnd(BeOkAndExpectation),
     BeErrAnd(BeErrAndExpectation),
+    MatchPatternAnd(MatchPatternAndExpectation),
 }
 
 impl Parse for InnerExpectation {
@@ -31,6 +32,10 @@ impl Parse for InnerExpectation {
             Ok(Self::BeOkAndAnd(input.parse::<BeOkAndExpectation>()?))
         } else if BeErrAndExpectation::peek(&input) {
             Ok(Self::BeErrAnd(input.parse::<BeErrAndExpectation>()?))
+        } else if MatchPatternAndExpectation::peek(&input) {
+            Ok(Self::MatchPatternAnd(
+                input.parse::<MatchPatternAndExpectation>()?,
+            ))
         } else {
             Ok(Self::Expression(input.parse::<ExpressionExpectation>()?))
         }
@@ -46,6 +51,7 @@ impl ExpectationType for InnerExpectation {
             Self::BeSomeAnd(expectation) => expectation.span(),
             Self::BeOkAndAnd(expectation) => expectation.span(),
             Self::BeErrAnd(expectation) => expectation.span(),
+            Self::MatchPatternAnd(expectation) => expectation.span(),
         }
     }
 
@@ -57,6 +63,7 @@ impl ExpectationType for InnerExpectation {
             Self::BeSomeAnd(expectation) => expectation.identifier_string(),
             Self::BeOkAndAnd(expectation) => expectation.identifier_string(),
             Self::BeErrAnd(expectation) => expectation.identifier_string(),
+            Self::MatchPatternAnd(expectation) => expectation.identifier_string(),
         }
     }
 
@@ -77,6 +84,7 @@ impl ExpectationType for InnerExpectation {
             Self::BeSomeAnd(expectation) => expectation.tokens(ident_prefix),
             Self::BeOkAndAnd(expectation) => expectation.tokens(ident_prefix),
             Self::BeErrAnd(expectation) => expectation.tokens(ident_prefix),
+            Self::MatchPatternAnd(expectation) => expectation.tokens(ident_prefix),
         }
     }
 
@@ -88,6 +96,7 @@ impl ExpectationType for InnerExpectation {
             Self::BeSomeAnd(expectation) => expectation.dependencies(),
             Self::BeOkAndAnd(expectation) => expectation.dependencies(),
             Self::BeErrAnd(expectation) => expectation.dependencies(),
+            Self::MatchPatternAnd(expectation) => expectation.dependencies(),
         }
     }
 }
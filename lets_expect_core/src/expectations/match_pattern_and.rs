@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Expr, Pat, Token,
+};
+
+use crate::{
+    core::keyword,
+    utils::{
+        expr_dependencies::expr_dependencies,
+        pattern_bindings::{parse_pattern, pattern_bindings, with_binding_values, PatternBinding},
+    },
+};
+
+use super::{
+    expectation_tokens::{AssertionTokens, ExpectationTokens, GroupAssertionTokens},
+    expectation_type::ExpectationType,
+    inner::InnerExpectation,
+};
+
+pub struct MatchPatternAndExpectation {
+    pattern: Pat,
+    guard: Option<Expr>,
+    bindings: Vec<PatternBinding>,
+    inner: Box<InnerExpectation>,
+    identifier_string: String,
+}
+
+impl MatchPatternAndExpectation {
+    pub(crate) fn new(pattern: Pat, guard: Option<Expr>, expectation: InnerExpectation) -> Self {
+        let identifier_string = format!("match_pattern_and_{}", expectation.identifier_string());
+        Self {
+            bindings: pattern_bindings(&pattern),
+            pattern,
+            guard,
+            inner: Box::new(expectation),
+            identifier_string,
+        }
+    }
+
+    pub fn peek(input: &ParseStream) -> bool {
+        let fork = input.fork();
+
+        fork.parse::<keyword::match_pattern>().is_ok()
+            && fork.parse::<Token![!]>().is_ok()
+            && fork.parse::<TokenTree>().is_ok()
+            && fork.peek(keyword::and)
+    }
+
+    pub fn span(&self) -> Span {
+        self.pattern.span()
+    }
+
+    pub fn identifier_string(&self) -> &str {
+        &self.identifier_string
+    }
+
+    pub fn dependencies(&self) -> HashSet<Ident> {
+        self.guard
+            .iter()
+            .flat_map(expr_dependencies)
+            .chain(self.inner.dependencies())
+            .filter(|dependency| {
+                !self
+                    .bindings
+                    .iter()
+                    .any(|binding| &binding.ident == dependency)
+            })
+            .collect()
+    }
+
+    pub(crate) fn tokens(&self, ident_prefix: &str) -> ExpectationTokens {
+        let inner_tokens = self.inner.tokens(ident_prefix, false, false);
+        let before_subject = inner_tokens.before_subject_evaluation;
+
+        let pattern = &self.pattern;
+        let bindings = self
+            .bindings
+            .iter()
+            .map(|binding| &binding.ident)
+            .collect::<Vec<&Ident>>();
+        // Like in a regular `match`, the guard and the nested expectations get the bound values
+        // where they're `Copy`, and references to them otherwise.
+        let path = quote! { lets_expect::__private };
+        let guard = self.guard.as_ref().map(|guard| {
+            let span = guard.span();
+            let guard = with_binding_values(&path, &self.bindings, guard.to_token_stream());
+            quote_spanned! { span => if #guard }
+        });
+        let values = with_binding_values(&path, &self.bindings, quote! { (#(#bindings,)*) });
+        let matched = quote_spanned! { self.span() =>
+            let Some((#(#bindings,)*)) = match &subject {
+                #pattern #guard => Some(#values),
+                _ => None,
+            }
+        };
+
+        // The bindings become fields of the new subject so that `have(binding.method())` works
+        // the same way it does for struct fields. They are also in scope as plain variables.
+        let generics = (0..bindings.len())
+            .map(|index| format_ident!("T{}", index))
+            .collect::<Vec<Ident>>();
+        let context = quote_spanned! { self.span() =>
+            #[allow(dead_code)]
+            struct MatchPatternBindings<#(#generics),*> {
+                #(#bindings: #generics),*
+            }
+
+            let subject = MatchPatternBindings { #(#bindings),* };
+        };
+
+        let argument = match &self.guard {
+            Some(guard) => format!(
+                "{} if {}",
+                pattern.to_token_stream(),
+                guard.to_token_stream()
+            ),
+            None => pattern.to_token_stream().to_string(),
+        };
+
+        let assertions = AssertionTokens::Group(GroupAssertionTokens::new(
+            "match_pattern".to_string(),
+            argument,
+            Some(matched),
+            Some(context),
+            inner_tokens.assertions,
+        ));
+
+        ExpectationTokens {
+            before_subject_evaluation: before_subject,
+            assertions,
+        }
+    }
+}
+
+impl Parse for MatchPatternAndExpectation {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<keyword::match_pattern>()?;
+        input.parse::<Token![!]>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let pattern = parse_pattern(&content)?;
+        let guard = if content.peek(Token![if]) {
+            content.parse::<Token![if]>()?;
+            Some(content.parse::<Expr>()?)
+        } else {
+            None
+        };
+
+        input.parse::<keyword::and>()?;
+        let inner = input.parse::<InnerExpectation>()?;
+
+        Ok(Self::new(pattern, guard, inner))
+    }
+}
@@ -10,9 +10,11 @@ mod have;
 mod inner;
 mod make;
 mod many;
+mod match_pattern_and;
 mod not_change;
 mod not_panic;
 mod panic;
+mod panic_with;
 mod return_value;
 
 pub(crate) mod expectation;
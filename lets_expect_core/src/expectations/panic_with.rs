@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote_spanned, ToTokens};
+use syn::{parse::Parse, spanned::Spanned, Expr, ExprLit, Lit, Token, Type};
+
+use crate::{
+    core::keyword,
+    utils::{
+        expr_dependencies::expr_dependencies,
+        parse_expression::parse_expr,
+        to_ident::{expr_to_ident, lit_str_to_ident, type_to_ident},
+    },
+};
+
+use super::expectation_tokens::{AssertionTokens, ExpectationTokens, SingleAssertionTokens};
+
+pub(crate) struct PanicWithExpectation {
+    span: Span,
+    payload_type: Option<Type>,
+    expression: Expr,
+    identifier_string: String,
+}
+
+impl PanicWithExpectation {
+    pub fn peek(input: &syn::parse::ParseStream) -> bool {
+        input.peek(keyword::panic_with) || input.peek(keyword::panic_with_payload)
+    }
+
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    pub fn identifier_string(&self) -> &str {
+        &self.identifier_string
+    }
+
+    pub(crate) fn tokens(&self) -> ExpectationTokens {
+        let expression = &self.expression;
+
+        let (label, assertion) = match &self.payload_type {
+            Some(payload_type) => (
+                format!(
+                    "panic_with_payload::<{}>({})",
+                    payload_type.to_token_stream(),
+                    expression.to_token_stream()
+                ),
+                quote_spanned! { self.span =>
+                    panic_with_payload::<#payload_type>(#expression)(&subject)
+                },
+            ),
+            None => (
+                format!("panic_with({})", expression.to_token_stream()),
+                quote_spanned! { self.span =>
+                    panic_with(#expression)(&subject)
+                },
+            ),
+        };
+
+        ExpectationTokens {
+            before_subject_evaluation: TokenStream::new(),
+            assertions: AssertionTokens::Single(SingleAssertionTokens::new(label, assertion)),
+        }
+    }
+
+    pub fn dependencies(&self) -> HashSet<Ident> {
+        expr_dependencies(&self.expression)
+    }
+}
+
+impl Parse for PanicWithExpectation {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(keyword::panic_with_payload) {
+            let keyword = input.parse::<keyword::panic_with_payload>()?;
+            input.parse::<Token![::]>()?;
+            input.parse::<Token![<]>()?;
+            let payload_type = input.parse::<Type>()?;
+            input.parse::<Token![>]>()?;
+            let expression = parse_expr(input)?;
+
+            Ok(Self {
+                span: keyword.span(),
+                identifier_string: format!("panic_with_payload_{}", type_to_ident(&payload_type)),
+                payload_type: Some(payload_type),
+                expression,
+            })
+        } else {
+            let keyword = input.parse::<keyword::panic_with>()?;
+            let expression = parse_expr(input)?;
+
+            Ok(Self {
+                span: keyword.span(),
+                identifier_string: format!("panic_with_{}", panic_with_ident(&expression)),
+                payload_type: None,
+                expression,
+            })
+        }
+    }
+}
+
+/// Names the test after the expected message when it's a string literal, so that two `panic_with`
+/// expectations in one context don't get the same name.
+fn panic_with_ident(expression: &Expr) -> String {
+    match expression {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(message),
+            ..
+        }) => lit_str_to_ident(message),
+        _ => expr_to_ident(expression),
+    }
+}
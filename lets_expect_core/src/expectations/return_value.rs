@@ -8,12 +8,13 @@ use super::{
     be_some_and::BeSomeAndExpectation, change::ChangeExpectation,
     expectation_tokens::ExpectationTokens, expectation_type::ExpectationType,
     expression::ExpressionExpectation, have::HaveExpectation, make::MakeExpectation,
-    many::ManyExpectation, not_change::NotChangeExpectation,
+    many::ManyExpectation, match_pattern_and::MatchPatternAndExpectation,
+    not_change::NotChangeExpectation,
 };
 
 pub(crate) enum ReturnValueExpectation {
     Expression(ExpressionExpectation),
-    Many(ManyExpectation<ReturnValueExpectation>),
+    Many(ManyExpectation<Self>),
     Have(HaveExpectation),
     Make(MakeExpectation),
     Change(ChangeExpectation),
@@ -21,6 +22,7 @@ pub(crate) enum ReturnValueExpectation {
     BeSomeAnd(BeSomeAndExpectation),
     BeOkAndAnd(BeOkAndExpectation),
     BeErrAnd(BeErrAndExpectation),
+    MatchPatternAnd(MatchPatternAndExpectation),
 }
 
 impl Parse for ReturnValueExpectation {
@@ -41,6 +43,10 @@ impl Parse for ReturnValueExpectation {
             Ok(Self::BeOkAndAnd(input.parse::<BeOkAndExpectation>()?))
         } else if BeErrAndExpectation::peek(&input) {
             Ok(Self::BeErrAnd(input.parse::<BeErrAndExpectation>()?))
+        } else if MatchPatternAndExpectation::peek(&input) {
+            Ok(Self::MatchPatternAnd(
+                input.parse::<MatchPatternAndExpectation>()?,
+            ))
         } else {
             Ok(Self::Expression(input.parse::<ExpressionExpectation>()?))
         }
@@ -59,6 +65,7 @@ impl ExpectationType for ReturnValueExpectation {
             Self::BeSomeAnd(expectation) => expectation.span(),
             Self::BeOkAndAnd(expectation) => expectation.span(),
             Self::BeErrAnd(expectation) => expectation.span(),
+            Self::MatchPatternAnd(expectation) => expectation.span(),
         }
     }
 
@@ -73,6 +80,7 @@ impl ExpectationType for ReturnValueExpectation {
             Self::BeSomeAnd(expectation) => expectation.identifier_string(),
             Self::BeOkAndAnd(expectation) => expectation.identifier_string(),
             Self::BeErrAnd(expectation) => expectation.identifier_string(),
+            Self::MatchPatternAnd(expectation) => expectation.identifier_string(),
         }
     }
 
@@ -87,6 +95,7 @@ impl ExpectationType for ReturnValueExpectation {
             Self::BeSomeAnd(expectation) => expectation.dependencies(),
             Self::BeOkAndAnd(expectation) => expectation.dependencies(),
             Self::BeErrAnd(expectation) => expectation.dependencies(),
+            Self::MatchPatternAnd(expectation) => expectation.dependencies(),
         }
     }
 
@@ -110,6 +119,7 @@ impl ExpectationType for ReturnValueExpectation {
             Self::BeSomeAnd(expectation) => expectation.tokens(ident_prefix),
             Self::BeOkAndAnd(expectation) => expectation.tokens(ident_prefix),
             Self::BeErrAnd(expectation) => expectation.tokens(ident_prefix),
+            Self::MatchPatternAnd(expectation) => expectation.tokens(ident_prefix),
         }
     }
 }
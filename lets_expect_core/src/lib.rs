@@ -16,4 +16,5 @@ pub mod assertions;
 pub mod core;
 pub mod execution;
 pub mod expectations;
+pub mod property;
 pub mod utils;
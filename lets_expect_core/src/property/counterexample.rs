@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use colored::Colorize;
+
+/// The simplest input found for a failing property-based test, along with what's needed to
+/// reproduce it.
+pub struct Counterexample {
+    pub seed: u64,
+    pub cases: usize,
+    pub shrinks: usize,
+    pub values: Vec<(String, String)>,
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = self
+            .values
+            .iter()
+            .map(|(name, value)| format!("    {} = {}", name, value.red().bold()))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        write!(
+            f,
+            "Falsified after {} cases and {} shrinks with seed {}\n{}\n{}\nRun with LETS_EXPECT_SEED={} to reproduce\n",
+            self.cases,
+            self.shrinks,
+            self.seed.to_string().yellow().bold(),
+            "Minimal counterexample:".cyan(),
+            values,
+            self.seed
+        )
+    }
+}
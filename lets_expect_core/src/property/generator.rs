@@ -0,0 +1,55 @@
+use std::fmt::Debug;
+
+use super::rng::Rng;
+
+/// Produces random values for a property-based `when` and simplifies the ones that fail.
+pub trait Generator {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Simpler variants of `value`, the most aggressive simplifications first.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+
+    /// One description per variable the value is bound to.
+    fn describe(&self, value: &Self::Value) -> Vec<String> {
+        vec![format!("{:?}", value)]
+    }
+}
+
+macro_rules! tuple_generator {
+    ($($generator:ident $index:tt),+) => {
+        impl<$($generator: Generator),+> Generator for ($($generator,)+) {
+            type Value = ($($generator::Value,)+);
+
+            fn generate(&self, rng: &mut Rng) -> Self::Value {
+                ($(self.$index.generate(rng),)+)
+            }
+
+            fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+                let mut candidates = Vec::new();
+                $(
+                    for shrunk in self.$index.shrink(&value.$index) {
+                        let mut candidate = value.clone();
+                        candidate.$index = shrunk;
+                        candidates.push(candidate);
+                    }
+                )+
+                candidates
+            }
+
+            fn describe(&self, value: &Self::Value) -> Vec<String> {
+                vec![$(format!("{:?}", value.$index)),+]
+            }
+        }
+    };
+}
+
+tuple_generator!(A 0);
+tuple_generator!(A 0, B 1);
+tuple_generator!(A 0, B 1, C 2);
+tuple_generator!(A 0, B 1, C 2, D 3);
+tuple_generator!(A 0, B 1, C 2, D 3, E 4);
+tuple_generator!(A 0, B 1, C 2, D 3, E 4, F 5);
+tuple_generator!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+tuple_generator!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
@@ -0,0 +1,314 @@
+//! Generators that can be used on the right side of `in` in a property-based `when`.
+
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+pub use super::generator::Generator;
+use super::rng::Rng;
+
+/// Length used when a range of lengths has no end, e.g. `string_of(1..)`.
+const DEFAULT_MAX_LENGTH: usize = 32;
+
+/// Characters outside of printable ASCII that generated strings sometimes contain.
+const SPECIAL_CHARS: [char; 8] = ['\0', '\t', '\n', 'é', 'ß', 'ж', '漢', '😀'];
+
+/// Types that can be generated with `any()`.
+pub trait Arbitrary: Sized + Clone + Debug {
+    fn arbitrary(rng: &mut Rng) -> Self;
+    fn shrink(&self) -> Vec<Self>;
+}
+
+/// Integers converted to and from `i128`, so that all of them share one implementation.
+pub trait Integer: Copy + Debug {
+    const MIN_VALUE: i128;
+    const MAX_VALUE: i128;
+
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! integer {
+    ($($type:ty),+) => {
+        $(
+            impl Integer for $type {
+                const MIN_VALUE: i128 = <$type>::MIN as i128;
+                const MAX_VALUE: i128 = <$type>::MAX as i128;
+
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(value: i128) -> Self {
+                    value as $type
+                }
+            }
+
+            impl Arbitrary for $type {
+                fn arbitrary(rng: &mut Rng) -> Self {
+                    Self::from_i128(arbitrary_integer(rng, Self::MIN_VALUE, Self::MAX_VALUE))
+                }
+
+                fn shrink(&self) -> Vec<Self> {
+                    shrink_integer(self.to_i128(), 0)
+                        .into_iter()
+                        .map(Self::from_i128)
+                        .collect()
+                }
+            }
+        )+
+    };
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Favours the boundaries and small values, where bugs tend to be.
+fn arbitrary_integer(rng: &mut Rng, min: i128, max: i128) -> i128 {
+    if rng.one_in(8) {
+        let edges = [0, 1, -1, min, max];
+        return edges[rng.below(edges.len() as u128) as usize].clamp(min, max);
+    }
+
+    let bits = rng.below(65) as u32;
+    let magnitude = (u128::from(rng.next_u64()) >> (64 - bits)) as i128;
+    let value = if min < 0 && rng.one_in(2) {
+        -magnitude
+    } else {
+        magnitude
+    };
+
+    value.clamp(min, max)
+}
+
+/// `target` first, then values closing the distance to it by a half, a quarter, ... and by one.
+fn shrink_integer(value: i128, target: i128) -> Vec<i128> {
+    let mut candidates = Vec::new();
+    let distance = value - target;
+
+    if distance != 0 {
+        candidates.push(target);
+    }
+
+    let mut step = distance / 2;
+    while step != 0 {
+        let candidate = value - step;
+        if !candidates.contains(&candidate) {
+            candidates.push(candidate);
+        }
+        step /= 2;
+    }
+
+    let last = value - distance.signum();
+    if distance != 0 && !candidates.contains(&last) {
+        candidates.push(last);
+    }
+
+    candidates
+}
+
+impl Arbitrary for bool {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        rng.one_in(2)
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl Arbitrary for String {
+    fn arbitrary(rng: &mut Rng) -> Self {
+        string_of(..).generate(rng)
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        string_of(..).shrink(self)
+    }
+}
+
+/// Generates any value of the type it's bound to, e.g. `x: u32 in any()`.
+pub struct Any<T> {
+    marker: PhantomData<fn() -> T>,
+}
+
+pub fn any<T: Arbitrary>() -> Any<T> {
+    Any {
+        marker: PhantomData,
+    }
+}
+
+impl<T: Arbitrary> Generator for Any<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        T::arbitrary(rng)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        value.shrink()
+    }
+}
+
+/// Generates integers from `low` to `high`, both inclusive.
+pub struct Between<T> {
+    low: T,
+    high: T,
+}
+
+pub fn between<T: Integer>(low: T, high: T) -> Between<T> {
+    Between { low, high }
+}
+
+impl<T: Integer> Generator for Between<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        let (low, high) = (self.low.to_i128(), self.high.to_i128());
+        if high <= low {
+            return self.low;
+        }
+
+        let span = (high - low) as u128 + 1;
+        T::from_i128(low + rng.below(span) as i128)
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        let (low, high) = (self.low.to_i128(), self.high.to_i128());
+
+        shrink_integer(value.to_i128(), 0_i128.clamp(low, high.max(low)))
+            .into_iter()
+            .map(T::from_i128)
+            .collect()
+    }
+}
+
+/// Generates strings with a number of characters in `lengths`.
+pub struct StringOf {
+    min: usize,
+    max: usize,
+}
+
+pub fn string_of(lengths: impl RangeBounds<usize>) -> StringOf {
+    let (min, max) = length_bounds(&lengths);
+    StringOf { min, max }
+}
+
+impl Generator for StringOf {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let length = generate_length(rng, self.min, self.max);
+
+        (0..length)
+            .map(|_| {
+                if rng.one_in(8) {
+                    SPECIAL_CHARS[rng.below(SPECIAL_CHARS.len() as u128) as usize]
+                } else {
+                    char::from(b' ' + rng.below(95) as u8)
+                }
+            })
+            .collect()
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let chars = value.chars().collect::<Vec<char>>();
+
+        shrink_sequence(&chars, self.min, |char| {
+            if *char == 'a' {
+                Vec::new()
+            } else {
+                vec!['a']
+            }
+        })
+        .into_iter()
+        .map(|chars| chars.into_iter().collect())
+        .collect()
+    }
+}
+
+/// Generates vectors of values of another generator, with a length in `lengths`.
+pub struct VecOf<G> {
+    element: G,
+    min: usize,
+    max: usize,
+}
+
+pub fn vec_of<G: Generator>(element: G, lengths: impl RangeBounds<usize>) -> VecOf<G> {
+    let (min, max) = length_bounds(&lengths);
+    VecOf { element, min, max }
+}
+
+impl<G: Generator> Generator for VecOf<G> {
+    type Value = Vec<G::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let length = generate_length(rng, self.min, self.max);
+
+        (0..length).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        shrink_sequence(value, self.min, |element| self.element.shrink(element))
+    }
+}
+
+/// Inclusive bounds of a range of lengths.
+fn length_bounds(lengths: &impl RangeBounds<usize>) -> (usize, usize) {
+    let min = match lengths.start_bound() {
+        Bound::Included(min) => *min,
+        Bound::Excluded(min) => min + 1,
+        Bound::Unbounded => 0,
+    };
+    let max = match lengths.end_bound() {
+        Bound::Included(max) => *max,
+        Bound::Excluded(max) => max.saturating_sub(1),
+        Bound::Unbounded => min + DEFAULT_MAX_LENGTH,
+    };
+
+    (min, max.max(min))
+}
+
+fn generate_length(rng: &mut Rng, min: usize, max: usize) -> usize {
+    min + rng.below((max - min) as u128 + 1) as usize
+}
+
+/// Shorter sequences first, removing halves and then single elements, followed by sequences with
+/// a single element simplified. Never goes below `min` elements.
+fn shrink_sequence<T: Clone>(
+    elements: &[T],
+    min: usize,
+    shrink_element: impl Fn(&T) -> Vec<T>,
+) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    let length = elements.len();
+
+    if length > min {
+        let half = (length / 2).max(min);
+        if half < length {
+            candidates.push(elements[..half].to_vec());
+            candidates.push(elements[length - half..].to_vec());
+        }
+
+        for index in 0..length {
+            let mut candidate = elements.to_vec();
+            candidate.remove(index);
+            candidates.push(candidate);
+        }
+    }
+
+    for (index, element) in elements.iter().enumerate() {
+        for shrunk in shrink_element(element) {
+            let mut candidate = elements.to_vec();
+            candidate[index] = shrunk;
+            candidates.push(candidate);
+        }
+    }
+
+    candidates
+}
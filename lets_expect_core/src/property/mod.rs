@@ -0,0 +1,5 @@
+pub mod counterexample;
+pub mod generator;
+pub mod generators;
+pub mod rng;
+pub mod run_property;
@@ -0,0 +1,45 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+/// A small, seedable pseudo-random number generator (SplitMix64). Property runs are reproducible
+/// by seeding it with the seed reported in a failure.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// A seed that differs between test runs.
+    pub fn random_seed() -> u64 {
+        RandomState::new().build_hasher().finish()
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    /// A value in `0..bound`, `0` if the bound is `0`.
+    pub fn below(&mut self, bound: u128) -> u128 {
+        if bound == 0 {
+            0
+        } else {
+            let value = (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64());
+            value % bound
+        }
+    }
+
+    /// `true` once in `n` calls on average.
+    pub fn one_in(&mut self, n: u128) -> bool {
+        self.below(n) == 0
+    }
+}
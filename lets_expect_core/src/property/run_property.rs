@@ -0,0 +1,88 @@
+use std::env;
+
+use crate::execution::{
+    executed_test_case::ExecutedTestCase, test_failure::TestFailure, test_result::TestResult,
+};
+
+use super::{counterexample::Counterexample, generator::Generator, rng::Rng};
+
+const DEFAULT_CASES: usize = 100;
+const MAX_SHRINK_RUNS: usize = 1000;
+
+/// Runs a test generated for a property-based `when` with `LETS_EXPECT_CASES` (100 by default)
+/// random inputs. The first failing input is shrunk to the simplest one that still fails.
+///
+/// The seed is random unless `LETS_EXPECT_SEED` is set.
+pub fn run_property<G: Generator>(
+    names: &[&str],
+    generator: G,
+    mut test: impl FnMut(G::Value) -> Vec<ExecutedTestCase>,
+) -> TestResult {
+    let seed = env_var("LETS_EXPECT_SEED").unwrap_or_else(Rng::random_seed);
+    let cases = env_var("LETS_EXPECT_CASES").unwrap_or(DEFAULT_CASES);
+    let mut rng = Rng::new(seed);
+
+    for case in 1..=cases {
+        let value = generator.generate(&mut rng);
+        let test_cases = test(value.clone());
+
+        if failed(&test_cases) {
+            let (value, test_cases, shrinks) = shrink(&generator, value, test_cases, &mut test);
+            let values = names
+                .iter()
+                .map(|name| name.to_string())
+                .zip(generator.describe(&value))
+                .collect();
+
+            return Err(
+                TestFailure::new(test_cases).with_counterexample(Counterexample {
+                    seed,
+                    cases: case,
+                    shrinks,
+                    values,
+                }),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn shrink<G: Generator>(
+    generator: &G,
+    mut value: G::Value,
+    mut test_cases: Vec<ExecutedTestCase>,
+    test: &mut impl FnMut(G::Value) -> Vec<ExecutedTestCase>,
+) -> (G::Value, Vec<ExecutedTestCase>, usize) {
+    let mut shrinks = 0;
+    let mut runs = 0;
+
+    'shrinking: while runs < MAX_SHRINK_RUNS {
+        for candidate in generator.shrink(&value) {
+            if runs == MAX_SHRINK_RUNS {
+                break 'shrinking;
+            }
+            runs += 1;
+
+            let candidate_cases = test(candidate.clone());
+            if failed(&candidate_cases) {
+                value = candidate;
+                test_cases = candidate_cases;
+                shrinks += 1;
+                continue 'shrinking;
+            }
+        }
+
+        break;
+    }
+
+    (value, test_cases, shrinks)
+}
+
+fn failed(test_cases: &[ExecutedTestCase]) -> bool {
+    test_cases.iter().any(ExecutedTestCase::failed)
+}
+
+fn env_var<T: std::str::FromStr>(name: &str) -> Option<T> {
+    env::var(name).ok().and_then(|value| value.parse().ok())
+}
@@ -15,6 +15,9 @@ pub(crate) fn expr_dependencies(expr: &Expr) -> HashSet<Ident> {
         Expr::Unary(unary) => {
             dependencies.extend(expr_dependencies(&unary.expr));
         }
+        Expr::Cast(cast) => {
+            dependencies.extend(expr_dependencies(&cast.expr));
+        }
         Expr::Assign(assign) => {
             dependencies.extend(expr_dependencies(&assign.left));
             dependencies.extend(expr_dependencies(&assign.right));
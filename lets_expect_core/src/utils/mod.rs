@@ -2,5 +2,6 @@ pub mod expr_dependencies;
 pub mod indent;
 pub mod mutable_token;
 pub mod parse_expression;
+pub mod pattern_bindings;
 pub mod reference_token;
 pub mod to_ident;
@@ -0,0 +1,119 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{parse::ParseStream, punctuated::Punctuated, Pat, PatOr, Token};
+
+/// Parses a pattern the way `match` arms do, with an optional leading `|`.
+pub fn parse_pattern(input: ParseStream) -> syn::Result<Pat> {
+    let leading_vert = input.parse::<Option<Token![|]>>()?;
+    let cases = Punctuated::<Pat, Token![|]>::parse_separated_nonempty(input)?;
+
+    if leading_vert.is_none() && cases.len() == 1 {
+        Ok(cases.into_iter().next().unwrap())
+    } else {
+        Ok(Pat::Or(PatOr {
+            attrs: Vec::new(),
+            leading_vert,
+            cases,
+        }))
+    }
+}
+
+/// A variable bound by a pattern matched on a reference.
+pub struct PatternBinding {
+    pub ident: Ident,
+    /// Whether the variable is a reference into the matched value. Variables under a `&` pattern
+    /// and `mut` ones are bound by value.
+    pub by_reference: bool,
+}
+
+/// Collects the variables bound by a pattern. Identifiers with `ref`, `mut` or `@` and struct
+/// field shorthands always bind. A bare identifier can also name a unit variant or a constant,
+/// like `None`, which the syntax alone doesn't tell apart. Those follow Rust's naming conventions,
+/// bindings are snake case.
+pub fn pattern_bindings(pattern: &Pat) -> Vec<PatternBinding> {
+    let mut bindings = Vec::new();
+    collect_bindings(pattern, true, &mut bindings);
+    bindings
+}
+
+fn collect_bindings(pattern: &Pat, by_reference: bool, bindings: &mut Vec<PatternBinding>) {
+    match pattern {
+        Pat::Ident(ident) => {
+            if ident.by_ref.is_some()
+                || ident.mutability.is_some()
+                || ident.subpat.is_some()
+                || is_snake_case(&ident.ident)
+            {
+                bindings.push(PatternBinding {
+                    ident: ident.ident.clone(),
+                    by_reference: by_reference
+                        && (ident.by_ref.is_some() || ident.mutability.is_none()),
+                });
+            }
+            if let Some((_, subpattern)) = &ident.subpat {
+                collect_bindings(subpattern, by_reference, bindings);
+            }
+        }
+        Pat::Box(pattern) => collect_bindings(&pattern.pat, by_reference, bindings),
+        Pat::Or(pattern) => {
+            if let Some(first) = pattern.cases.first() {
+                collect_bindings(first, by_reference, bindings);
+            }
+        }
+        Pat::Reference(pattern) => collect_bindings(&pattern.pat, false, bindings),
+        Pat::Slice(pattern) => pattern
+            .elems
+            .iter()
+            .for_each(|pattern| collect_bindings(pattern, by_reference, bindings)),
+        Pat::Struct(pattern) => pattern.fields.iter().for_each(|field| match &*field.pat {
+            Pat::Ident(ident) if field.colon_token.is_none() => {
+                bindings.push(PatternBinding {
+                    ident: ident.ident.clone(),
+                    by_reference: by_reference
+                        && (ident.by_ref.is_some() || ident.mutability.is_none()),
+                });
+            }
+            pattern => collect_bindings(pattern, by_reference, bindings),
+        }),
+        Pat::Tuple(pattern) => pattern
+            .elems
+            .iter()
+            .for_each(|pattern| collect_bindings(pattern, by_reference, bindings)),
+        Pat::TupleStruct(pattern) => pattern
+            .pat
+            .elems
+            .iter()
+            .for_each(|pattern| collect_bindings(pattern, by_reference, bindings)),
+        Pat::Type(pattern) => collect_bindings(&pattern.pat, by_reference, bindings),
+        _ => {}
+    }
+}
+
+fn is_snake_case(ident: &Ident) -> bool {
+    ident
+        .to_string()
+        .trim_start_matches('_')
+        .starts_with(|char: char| char.is_lowercase())
+}
+
+/// Evaluates `expression` with the variables bound by reference turned into values where their
+/// type is `Copy`, using the `Binding` helpers found at `path`.
+pub fn with_binding_values(
+    path: &TokenStream,
+    bindings: &[PatternBinding],
+    expression: TokenStream,
+) -> TokenStream {
+    let idents = bindings
+        .iter()
+        .filter(|binding| binding.by_reference)
+        .map(|binding| &binding.ident);
+
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use #path::{CopyBinding as _, RefBinding as _};
+            #(#[allow(unused_variables)] let #idents = (&#path::Binding(#idents)).value();)*
+            #expression
+        }
+    }
+}
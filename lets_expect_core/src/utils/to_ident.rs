@@ -126,7 +126,7 @@ pub fn expr_to_ident(expr: &syn::Expr) -> String {
     }
 }
 
-fn type_to_ident(ty: &Type) -> String {
+pub fn type_to_ident(ty: &Type) -> String {
     match ty {
         Type::Array(array) => format!(
             "array_of_{}_{}",
@@ -213,12 +213,28 @@ pub fn punctuated_to_ident(punctuated: &Punctuated<Expr, Comma>) -> String {
 //     punctuated.iter().map(|assignment| format!("{}_is_{}", assignment.name, expr_to_ident(&assignment.value))).collect::<Vec<String>>().join("_and_")
 // }
 
+/// The words of a string literal, e.g. `index_out_of_bounds` for `"Index out of bounds!"`.
+pub fn lit_str_to_ident(lit: &syn::LitStr) -> String {
+    let words = lit
+        .value()
+        .split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<String>>();
+
+    if words.is_empty() {
+        "string".to_string()
+    } else {
+        words.join("_")
+    }
+}
+
 fn expr_lit_to_ident(lit: &syn::ExprLit) -> String {
     match &lit.lit {
         Lit::Str(_) => "string".to_string(),
         Lit::ByteStr(_) => unimplemented!(),
         Lit::Byte(_) => unimplemented!(),
-        Lit::Char(_) => unimplemented!(),
+        Lit::Char(_) => "char".to_string(),
         Lit::Int(value) => {
             if let Ok(parsed) = value.base10_parse::<i64>() {
                 humanize(parsed)
@@ -14,7 +14,12 @@
 
 extern crate proc_macro;
 
-use lets_expect_core::core::{context::Context, runtime::Runtime};
+use lets_expect_core::core::{
+    context::Context,
+    pattern_guard::PatternGuard,
+    runtime::Runtime,
+    shared::{include_shared, IncludedShared, SharedMacros},
+};
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
@@ -22,7 +27,17 @@ use syn::parse_macro_input;
 
 #[proc_macro]
 pub fn lets_expect(input: TokenStream) -> TokenStream {
-    lets_expect_macro(input)
+    match include_shared(input.into()) {
+        IncludedShared::Expanded(input) => lets_expect_macro(input.into()),
+        IncludedShared::Missing(call) => call.into(),
+    }
+}
+
+#[proc_macro]
+pub fn lets_expect_shared(input: TokenStream) -> TokenStream {
+    let shared = parse_macro_input!(input as SharedMacros);
+
+    shared.to_tokens().into()
 }
 
 fn lets_expect_macro(input: TokenStream) -> TokenStream {
@@ -36,3 +51,10 @@ fn lets_expect_macro(input: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+/// Used by `match_pattern!` to evaluate its guard, not part of the public API.
+#[doc(hidden)]
+#[proc_macro]
+pub fn match_pattern_guard(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as PatternGuard).to_tokens().into()
+}
@@ -28,17 +28,27 @@
 //!     * [`make`](#make)
 //!     * [`change`](#change)
 //!     * [`before` and `after`](#before-and-after)
+//!     * [`before_all` and `after_all`](#before_all-and-after_all)
+//!     * [`around`](#around)
 //!     * [Explicit identifiers for `expect` and `when`](#explicit-identifiers-for-expect-and-when)
 //!     * [Stories](#stories)
+//!     * [Shared contexts](#shared-contexts)
+//!     * [Attributes](#attributes)
+//!     * [Pending and skipped tests](#pending-and-skipped-tests)
+//!     * [Tags](#tags)
 //!     * [Mutable variables and references](#mutable-variables-and-references)
 //! 4. [Assertions](#assertions)
 //!     * [`bool`](#bool)
 //!     * [`equality`](#equality)
 //!     * [Numbers](#numbers)
+//!     * [Strings](#strings)
+//!     * [Collections](#collections)
 //!     * [`match_pattern!`](#match_pattern)
 //!     * [`Option` and `Result`](#option_and_result)
 //!     * [`panic`](#panic)
 //!     * [Iterators](#iterators)
+//!     * [Combining assertions](#combining-assertions)
+//!     * [Snapshots](#snapshots)
 //!     * [Custom assertions](#custom-assertions)
 //!     * [Custom `change` assertions](#custom-change-assertions)
 //!     * [Assertions module](#assertions-module)
@@ -494,6 +504,91 @@
 //! # tests::when_a_is_two::expect_a_plus_two::to_equal_four().unwrap();
 //! ```
 //!
+//! To run the same tests for many inputs use `when each`. It creates a separate `when` for every row of the table, named after the values in the row.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::lets_expect;
+//! # lets_expect! { #method
+//! expect(a + b) {
+//!     when each (a, b, sum) in [(1, 2, 3), (3, 5, 8), (-1, 1, 0)] {
+//!         to equal(sum)
+//!     }
+//! }
+//! # }
+//! # }
+//! # tests::expect_a_plus_b::when_a_is_one_b_is_two_sum_is_three::to_equal_sum().unwrap();
+//! # tests::expect_a_plus_b::when_a_is_three_b_is_five_sum_is_eight::to_equal_sum().unwrap();
+//! # tests::expect_a_plus_b::when_a_is_neg_one_b_is_one_sum_is_zero::to_equal_sum().unwrap();
+//! ```
+//!
+//! The example above generates three tests: `when_a_is_one_b_is_two_sum_is_three::to_equal_sum`, `when_a_is_three_b_is_five_sum_is_eight::to_equal_sum` and `when_a_is_neg_one_b_is_one_sum_is_zero::to_equal_sum`.
+//!
+//! Rows can also come from a JSON file holding an array of objects or from a CSV file with a header. The path is relative to the crate's directory
+//! and the file is read when the tests are compiled. Every field of a row becomes a variable. Use a tuple, e.g. `each (a, sum) in file(...)`, to pick only some of them.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::lets_expect;
+//! # lets_expect! { #method
+//! expect(a + b) {
+//!     when each row in file("tests/data/cases.json") {
+//!         to equal(sum)
+//!     }
+//! }
+//! # }
+//! # }
+//! # tests::expect_a_plus_b::when_a_is_one_b_is_two_sum_is_three::to_equal_sum().unwrap();
+//! ```
+//!
+//! Values in CSV files that look like numbers, e.g. `-12`, `1_000` or `2.5e3`, or booleans become numbers and booleans.
+//! Anything else, like `+5` or `inf`, stays a string. Quoted values can contain commas and line breaks.
+//! A missing or malformed file fails the compilation with an error pointing at its path, and so does a `null` in a JSON file, with an error naming its field and row.
+//!
+//! To test every combination of a few values give the variables lists of values with `in`. Integer ranges work too.
+//! Every combination gets its own nested `when`, e.g. `when_role_is_admin::when_level_is_two`.
+//!
+//! ```
+//! # mod tests {
+//! # #[derive(Clone, Copy)]
+//! # pub enum Role { Admin, User, Guest }
+//! # pub use Role::*;
+//! # pub fn can_delete(_role: Role, _level: u8) -> bool { true }
+//! # use lets_expect::lets_expect;
+//! # lets_expect! { #method
+//! expect(can_delete(role, level)) {
+//!     when(role in [Admin, User, Guest], level in 0..4) {
+//!         to be_true
+//!     }
+//! }
+//! # }
+//! # }
+//! # tests::expect_can_delete_role_level::when_role_is_admin::when_level_is_two::to_be_true().unwrap();
+//! # tests::expect_can_delete_role_level::when_role_is_guest::when_level_is_zero::to_be_true().unwrap();
+//! ```
+//!
+//! To check a property for many random inputs bind the variables to generators instead of values: `any()`, `between(low, high)`,
+//! `string_of(lengths)` and `vec_of(generator, lengths)`. The test runs 100 times with new values, `before` and `after` included.
+//!
+//! ```
+//! # mod tests {
+//! # pub fn reverse(s: &str) -> String { s.chars().rev().collect() }
+//! # use lets_expect::lets_expect;
+//! # lets_expect! { #method
+//! expect(reverse(&reverse(&s))) {
+//!     when(s in string_of(0..20), n: u8 in any()) {
+//!         to equal(s.clone())
+//!     }
+//! }
+//! # }
+//! # }
+//! # tests::expect_reverse_reverse_s::when_s_is_string_of_range_from_zero_to_twenty_n_is_any::to_equal_s_clone().unwrap();
+//! ```
+//!
+//! When the test fails the values are shrunk to the simplest ones that still fail. The failure reports them along with the seed.
+//! Set `LETS_EXPECT_SEED` to rerun the test with the same values and `LETS_EXPECT_CASES` to change the number of runs.
+//! Generators can't use variables defined with `let`.
+//!
 //! ### `have`
 //!
 //! `have` is used to test values of attributes or return values of methods of the subject.
@@ -608,13 +703,44 @@
 //! # tests::expect_posts_create_post_title_category_id::when_title_is_valid_title::when_category_id_is_invalid_category::to_not_change_posts_len().unwrap();
 //! ```
 //!
+//! Besides `from`, `to` and `by` the change can be checked with `increase`, `decrease`, `by_at_least`, `by_at_most`, `by_between` and `by_approximately`.
+//! The `by_*` assertions look at the difference between the two values, so they also work with types like `Instant` where the difference is a `Duration`.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # lets_expect! { #method
+//! let mut counter = 0;
+//!
+//! expect(counter += 3) as adding {
+//!     to grow_by_a_few {
+//!         change(counter) { increase, by_at_least(2), by_at_most(5), by_between(1, 3) }
+//!     }
+//! }
+//!
+//! expect(counter -= 1) as subtracting {
+//!     to shrink {
+//!         change(counter) decrease
+//!     }
+//! }
+//! # }
+//! # }
+//! # tests::expect_adding::to_grow_by_a_few().unwrap();
+//! # tests::expect_subtracting::to_shrink().unwrap();
+//! ```
+//!
 //! ### `before` and `after`
 //!
 //! The contents of the `before` blocks are executed before the subject is evaluated, but after the `let` bindings are executed. The contents of the `after` blocks are executed
 //! after the subject is evaluated and the assertions are verified.
 //!
 //! `before` blocks are run in the order they are defined. Parent `before` blocks being run before child `before` blocks. The reverse is true for `after` blocks.
-//! `after` blocks are guaranteed to run even if assertions fail. They however will not run if the let statements, before blocks, subject evaluation or assertions panic.
+//! `after` blocks are guaranteed to run even if assertions fail or the let statements, before blocks, subject evaluation or assertions panic.
+//! If a let statement panics, `after` blocks using it or the lets defined after it are skipped.
+//! A panic doesn't unwind out of the test. It fails the test like a failed assertion, naming where it happened, e.g. `Panicked in let value: "not a number"`,
+//! `before block 1`, `subject`, `assertion` or `after block 2`. `before` and `after` blocks are numbered in the order they run in.
+//! A panic inside of an `after` block is reported the same way, the remaining `after` blocks still run.
+//! In async tests, `#method_async` and `#tokio_test`, panics can't be caught, so they aren't reported this way. They unwind as usual without naming where they happened, and `after` blocks don't run after a panic.
 //!
 //! ```
 //! # mod tests {
@@ -637,6 +763,87 @@
 //! # tests::expect_messages_push_string::to_change_messages_len_from_one_and_to_two().unwrap();
 //! ```
 //!
+//! ### `before_all` and `after_all`
+//!
+//! `before` and `after` blocks run in every test. Expensive setup can be done once per module with `before_all` instead. A `before_all` can
+//! bind its value to a variable, which is then available to all the tests in the module as a `&'static` reference. The type of the
+//! variable has to be given and it has to be `Send` and `Sync` since the tests can run in parallel.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # pub struct Index(Vec<&'static str>);
+//! # impl Index {
+//! #     pub fn build(documents: &[&'static str]) -> Self { Self(documents.to_vec()) }
+//! #     pub fn search(&self, word: &str) -> Vec<usize> {
+//! #         self.0.iter().enumerate().filter(|(_, document)| document.contains(word)).map(|(index, _)| index).collect()
+//! #     }
+//! # }
+//! # fn load_documents() -> Vec<&'static str> { vec!["go", "rust", "rust and go"] }
+//! # fn start_server() {}
+//! # fn stop_server() {}
+//! # lets_expect! { #method
+//! expect(index.search("rust")) {
+//!     before_all index: Index {
+//!         Index::build(&load_documents())
+//!     }
+//!
+//!     before_all {
+//!         start_server();
+//!     }
+//!
+//!     after_all {
+//!         stop_server();
+//!     }
+//!
+//!     to equal(vec![1, 2])
+//! }
+//! # }
+//! # }
+//! # tests::expect_index_search_string::to_equal_vec().unwrap();
+//! ```
+//!
+//! `before_all` runs in the first test of the module that starts, the other tests wait for it to finish. It can use the values of the `before_all`
+//! blocks of the modules around it and of the blocks above it.
+//!
+//! `after_all` is best-effort. It runs at most once and only if `before_all` did. The tests of the module are counted when the macro expands, so it runs
+//! after the last of them only if all of them run. If some don't, because they are ignored, filtered out by `cargo test` or removed with `#[cfg]`,
+//! it runs when the test process exits instead, where a panic can't fail a test anymore. The same goes for `#method` tests that aren't all called.
+//! Exiting the process only runs the remaining `after_all` blocks on Unix and Windows.
+//!
+//! ### `around`
+//!
+//! Some setup has to wrap the test rather than precede it, e.g. holding a lock or running inside of a scope. `around` takes a closure
+//! which gets a function running the test: the lets, befores, the expectations and the afters. The closure has to call it once.
+//! `around` hooks of outer contexts wrap the ones of inner contexts.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # use std::sync::Mutex;
+//! # static ENVIRONMENT: Mutex<()> = Mutex::new(());
+//! # fn read_config() -> Result<String, ()> { std::env::var("CONFIG").map_err(|_| ()) }
+//! # fn test_config() -> String { "test.toml".to_string() }
+//! # lets_expect! { #method
+//! expect(read_config()) {
+//!     around |run| {
+//!         let _guard = ENVIRONMENT.lock().unwrap();
+//!         run();
+//!     }
+//!
+//!     before {
+//!         std::env::set_var("CONFIG", "test.toml");
+//!     }
+//!
+//!     to equal(Ok(test_config()))
+//! }
+//! # }
+//! # }
+//! # tests::expect_read_config::to_equal_ok_test_config().unwrap();
+//! ```
+//!
+//! `around` is not supported in async tests.
+//!
 //! ### Explicit identifiers for `expect` and `when`
 //!
 //! Because `lets_expect` uses standard Rust tests under the hood it has to come up with a unique identifier for each test. To make those identifiers
@@ -743,6 +950,203 @@
 //! > **NOTE:**  For now `expect` blocks can't be placed inside of loops or closures. They need to be top-level items in a story.
 //! >
 //!
+//! ### Shared contexts
+//!
+//! Expectations that apply to many subjects can be defined once with `shared` and included in any `expect` or `when` with `behaves_like`.
+//! The `let`s, `before`s, `after`s and expectations of the shared context become part of the context including it. Its `let`s can be overridden there.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::lets_expect;
+//! # lets_expect! { #method
+//! expect(vec.clone()) {
+//!     shared a_collection_of_three {
+//!         let expected_len = 3;
+//!
+//!         to have(len()) equal(expected_len)
+//!         to not(be_empty)
+//!     }
+//!
+//!     when(vec = vec![1, 2, 3]) as vec_of_numbers {
+//!         behaves_like a_collection_of_three
+//!     }
+//!
+//!     when(vec = vec!["a", "b"]) as vec_of_two_strings {
+//!         let expected_len = 2;
+//!
+//!         behaves_like a_collection_of_three
+//!     }
+//! }
+//! # }
+//! # }
+//! # tests::expect_vec_clone::when_vec_of_numbers::to_have_len_equal_expected_len().unwrap();
+//! # tests::expect_vec_clone::when_vec_of_two_strings::to_have_len_equal_expected_len().unwrap();
+//! ```
+//!
+//! A shared context is visible in the context it's defined in and in all of its children.
+//!
+//! To share contexts between modules or crates define them with `lets_expect_shared!` and include them with `include_shared!`.
+//! Every shared context becomes a `#[macro_export]` macro. Other crates include it by its path, e.g. `include_shared!(my_crate::behaves_like_a_repository)`.
+//! The crate defining it can't use that path, `include_shared!(crate::behaves_like_a_repository)` doesn't compile. There the definition has to come
+//! before the tests, like for any `macro_rules!` macro, in a module marked with `#[macro_use]` if it has one of its own, and it's included by name.
+//!
+//! ```
+//! #[macro_use]
+//! mod shared_contexts {
+//! #   use lets_expect::*;
+//!     lets_expect_shared! {
+//!         behaves_like_a_repository {
+//!             to have(count()) equal(0)
+//!
+//!             expect(repository.insert(1)) as inserting {
+//!                 let mut repository = repository.clone();
+//!
+//!                 to change(repository.count()) { from(0), to(1) }
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! # mod tests {
+//! # use lets_expect::*;
+//! # #[derive(Clone, Default)]
+//! # pub struct MemoryRepository { items: Vec<i32> }
+//! # impl MemoryRepository {
+//! #     pub fn count(&self) -> usize { self.items.len() }
+//! #     pub fn insert(&mut self, item: i32) { self.items.push(item) }
+//! # }
+//! # lets_expect! { #method
+//! # expect(repository.clone()) {
+//! #     let repository = MemoryRepository::default();
+//! #
+//! #     include_shared!(behaves_like_a_repository)
+//! # }
+//! # }
+//! # }
+//! # tests::expect_repository_clone::to_have_count_equal_zero().unwrap();
+//! # tests::expect_repository_clone::expect_inserting::to_change_repository_count_from_zero_and_to_one().unwrap();
+//! ```
+//!
+//! ### Attributes
+//!
+//! Attributes can be put on `expect`, `when`, `to` and `story`. Attributes that apply to the whole module, like `#[cfg]` or `#[allow]`,
+//! are put on the module created for `expect` or `when`. All others, like `#[ignore]` or `#[should_panic]`, are put on every test inside of it.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # lets_expect! { #method
+//! expect(a + b) as sum {
+//!     let a = 1;
+//!     let b = 2;
+//!
+//!     #[should_panic]
+//!     to equal(4)
+//!
+//!     #[ignore]
+//!     when(b = 1000) to equal(1001)
+//!
+//!     #[cfg(feature = "slow")]
+//!     when(b = slow_computation()) to equal(3)
+//! }
+//! # }
+//! # }
+//! # assert!(tests::expect_sum::to_equal_four().is_err());
+//! # tests::expect_sum::when_b_is_onethousand::to_equal_onethousandone().unwrap();
+//! ```
+//!
+//! Attributes coming from other crates work as well, e.g. `#[serial]` from [serial_test](https://crates.io/crates/serial_test).
+//! `#[ignore]` and `#[should_panic]` are dropped in `#method` mode since the generated functions aren't tests.
+//!
+//! ### Pending and skipped tests
+//!
+//! Expectations that aren't implemented yet can be marked as `pending`. A pending test passes as long as it fails, printing a `PENDING` line instead.
+//! Once the code is implemented and the test starts passing it fails, reminding you to remove `pending`.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # fn parse(_: &str) -> Result<i32, ()> { todo!() }
+//! # lets_expect! { #method
+//! expect(parse("1 + 2")) {
+//!     pending to equal(Ok(3))
+//! }
+//! # }
+//! # }
+//! # tests::expect_parse_string::to_equal_ok_three().unwrap();
+//! ```
+//!
+//! Prefixing `to`, `when` or `expect` with an `x` skips it. The tests are generated with `#[ignore]` and don't run unless asked to with `cargo test -- --ignored`.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # fn slow_computation() -> i32 { 3 }
+//! # lets_expect! {
+//! expect(a + b) as sum {
+//!     let a = 1;
+//!     let b = 2;
+//!
+//!     xto equal(4)
+//!     xwhen(b = 3) to equal(5)
+//! }
+//!
+//! xexpect(slow_computation()) to equal(3)
+//! # }
+//! # }
+//! ```
+//!
+//! Tests that can only run in some environments can be skipped with `skip_if`. The condition is checked before any `let` is evaluated.
+//! A skipped test passes and prints the reason to stderr, even though the test harness hides the output of passing tests, so that it's visible which tests didn't run. `skip_if` applies to all the tests in its context, including nested ones.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # fn which(_: &str) -> Result<(), ()> { Err(()) }
+//! # fn convert(_: &str) -> Result<(), ()> { Err(()) }
+//! # lets_expect! { #method
+//! expect(convert("image.png")) {
+//!     skip_if(which("convert").is_err(), "ImageMagick is not installed")
+//!
+//!     to be_ok
+//! }
+//! # }
+//! # }
+//! # tests::expect_convert_string::to_be_ok().unwrap();
+//! ```
+//!
+//! ### Tags
+//!
+//! `expect`, `when`, `to` and `story` can be tagged with `#tag`. Tags are inherited by all the tests inside.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # #[derive(Debug)]
+//! # pub struct Repository(usize);
+//! # impl Repository { pub fn count(&self) -> usize { self.0 } }
+//! # fn seeded_repository() -> Repository { Repository(1000) }
+//! # lets_expect! { #method
+//! # let repository = Repository(0);
+//! #tag(db)
+//! expect(repository.count()) {
+//!     to equal(0)
+//!
+//!     #tag(slow)
+//!     when(repository = seeded_repository()) to equal(1000)
+//! }
+//! # }
+//! # }
+//! # tests::expect_repository_count::to_equal_zero().unwrap();
+//! ```
+//!
+//! The tests to run are selected with environment variables holding comma separated tags. With `LETS_EXPECT_TAGS` only the tests with at least one of
+//! the tags run. With `LETS_EXPECT_EXCLUDE_TAGS` the tests with any of the tags don't run. Tests that don't run are reported as skipped.
+//!
+//! ```sh
+//! LETS_EXPECT_EXCLUDE_TAGS=slow,db cargo test
+//! ```
+//!
 //! ### Mutable variables and references
 //!
 //! For some tests you may need to make the tested value mutable or you may need to pass a mutable reference to the assertions. In `expect`, `have` and `make` you can
@@ -805,6 +1209,9 @@
 //! # tests::expect_two::to_be_actually_two().unwrap();
 //! ```
 //!
+//! When the expected or received value spans multiple lines when pretty-printed (structs, long `Vec`s) `equal` shows a line-by-line diff
+//! of the two values instead. Long unchanged regions are folded.
+//!
 //! ### Numbers
 //!
 //! ```
@@ -823,6 +1230,88 @@
 //! # tests::expect_two_point_ten::to_be_less_or_equal_to_two_point_ten().unwrap();
 //! ```
 //!
+//! For floating-point numbers prefer a relative tolerance or a distance in ULPs (units in the last place) over an absolute delta.
+//! Both work for values of any magnitude and never consider `NaN` close to anything. Failure messages show the actual difference.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # lets_expect! { #method
+//! expect(0.1 + 0.2) as sum {
+//!    to be_approximately(0.3, 1e-12)
+//!    to be_within_ulps(0.3, 1)
+//!    to be_finite
+//! }
+//!
+//! expect(f64::NAN) as nan {
+//!    to be_nan
+//! }
+//!
+//! expect(1.0 / 0.0) as division_by_zero {
+//!    to be_infinite
+//! }
+//! # }
+//! # }
+//! # tests::expect_sum::to_be_approximately_zero_point_thirty_zero_point_zero().unwrap();
+//! # tests::expect_sum::to_be_within_ulps_zero_point_thirty_one().unwrap();
+//! # tests::expect_sum::to_be_finite().unwrap();
+//! # tests::expect_nan::to_be_nan().unwrap();
+//! # tests::expect_division_by_zero::to_be_infinite().unwrap();
+//! ```
+//!
+//! ### Strings
+//!
+//! String assertions work with anything that implements `AsRef<str>`. `contain` takes either a substring or a `char`.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # lets_expect! { #method
+//! expect("Hello, world!") {
+//!     to be_a_greeting {
+//!         contain("world"),
+//!         start_with("Hello"),
+//!         end_with("!"),
+//!         have_char_count(13),
+//!         match_regex(r"^\w+, \w+!$")
+//!     }
+//! }
+//!
+//! expect(" \t ") as whitespace to be_blank
+//! # }
+//! # }
+//! # tests::expect_string::to_be_a_greeting().unwrap();
+//! # tests::expect_whitespace::to_be_blank().unwrap();
+//! ```
+//!
+//! ### Collections
+//!
+//! Collection assertions work with anything that can be iterated over by reference, like `Vec`, slices, `HashSet` or `BTreeMap`.
+//! When they fail they list the elements that are missing or unexpected.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # lets_expect! { #method
+//! expect(vec![1, 2, 3]) {
+//!     to contain_numbers {
+//!         contain(2),
+//!         contain_all_of([3, 1]),
+//!         contain_exactly([1, 2, 3]),
+//!         contain_in_any_order([3, 2, 1]),
+//!         have_len(3)
+//!     }
+//! }
+//!
+//! expect(Vec::<u8>::new()) to be_empty
+//! # }
+//! # }
+//! # tests::expect_vec::to_contain_numbers().unwrap();
+//! # tests::expect_vec_new::to_be_empty().unwrap();
+//! ```
+//!
+//! Maps are compared using `(key, value)` pairs, e.g. `contain((1, "one"))`.
+//!
 //! ### `match_pattern!`
 //!
 //! `match_pattern!` is used to test if a value matches a pattern. It's functionality is similar to [`matches!`](https://doc.rust-lang.org/std/macro.matches.html) macro.
@@ -852,6 +1341,35 @@
 //! # tests::expect_response_validationfailed_string::to_match_email().unwrap();
 //! ```
 //!
+//! Patterns can have an `if` guard. To run further assertions on the parts of a value follow `match_pattern!` with `and`.
+//! The variables bound by the pattern become fields of the new subject, so `have` can reach them.
+//! As in a regular `match`, the guard and the nested assertions get the bound values when they are `Copy` and references to them otherwise.
+//!
+//! ```
+//! # mod tests {
+//! # #[derive(Clone, Debug, PartialEq)]
+//! # pub enum Event {
+//! #     Created { id: u32 },
+//! # }
+//! # #[derive(Clone, Debug, PartialEq)]
+//! # pub enum Response {
+//! #     Ok(String),
+//! # }
+//! # use lets_expect::lets_expect;
+//! # lets_expect! { #method
+//! expect(Event::Created { id: 7 }) as created {
+//!     to match_pattern!(Event::Created { id } if id > 0)
+//! }
+//!
+//! expect(Response::Ok("abc".to_string())) as ok {
+//!     to match_pattern!(Response::Ok(body)) and have(body.len()) equal(3)
+//! }
+//! # }
+//! # }
+//! # tests::expect_created::to_match_pattern().unwrap();
+//! # tests::expect_ok::to_match_pattern_and_have_body_len_equal_three().unwrap();
+//! ```
+//!
 //! ### `Option` and `Result`
 //!
 //! `lets_expect` provides a set of assertions for `Option` and `Result` types.
@@ -922,6 +1440,27 @@
 //!
 //! `panic` and `not_panic` assertions can be the only assertions present in a `to` block.
 //!
+//! `panic_with` checks that the panic message contains the given string. `panic_with_payload` downcasts the panic payload to the given type and runs an assertion against it. Both show the actual panic message when they fail.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # lets_expect! { #method
+//! expect(panic!("index out of bounds")) {
+//!     to panic_with("out of bounds")
+//! }
+//!
+//! expect(std::panic::panic_any(404u16)) as not_found {
+//!     to panic_with_payload::<u16>(equal(404))
+//! }
+//! # }
+//! # }
+//! # tests::expect_panic::to_panic_with_out_of_bounds().unwrap();
+//! # tests::expect_not_found::to_panic_with_payload_u16().unwrap();
+//! ```
+//!
+//! Subjects expected to panic run without printing the usual "thread panicked" message to stderr. A panic hook silencing them is installed while they run and the previous hook is restored afterwards. Panics on other threads still reach the previous hook. When `panic_with` or `panic_with_payload` fail, the report includes the location of the panic instead.
+//!
 //!
 //! ### Iterators
 //!
@@ -932,11 +1471,68 @@
 //! expect(vec![1, 2, 3]) {
 //!    to have(mut iter()) all(be_greater_than(0))
 //!    to have(mut iter()) any(be_greater_than(2))
+//!    to have(mut iter()) none(be_greater_than(3))
+//!    to have(mut iter()) exactly(2, be_greater_than(1))
+//!    to have(mut iter()) at_least(1, be_greater_than(2))
+//!    to have(mut iter()) at_most(1, be_greater_than(2))
 //! }
 //! # }
 //! # }
 //! # tests::expect_vec::to_have_mut_iter_all_be_greater_than_zero().unwrap();
 //! # tests::expect_vec::to_have_mut_iter_any_be_greater_than_two().unwrap();
+//! # tests::expect_vec::to_have_mut_iter_none_be_greater_than_three().unwrap();
+//! # tests::expect_vec::to_have_mut_iter_exactly_two_be_greater_than_one().unwrap();
+//! # tests::expect_vec::to_have_mut_iter_at_least_one_be_greater_than_two().unwrap();
+//! # tests::expect_vec::to_have_mut_iter_at_most_one_be_greater_than_two().unwrap();
+//! ```
+//!
+//! Iterator assertions accept any iterator over references, e.g. `have(mut keys())` on a `HashMap`. Failures list the index and value
+//! of every offending element, so the elements have to implement `Debug`. `all` and `any` didn't require that before, iterators over
+//! elements without a `Debug` implementation no longer work with them.
+//!
+//! ### Combining assertions
+//!
+//! Assertions can be combined using `not`, `both`, `either` and `one_of`. `one_of` passes if at least one of the given assertions passes.
+//!
+//! ```
+//! # mod tests {
+//! # use lets_expect::*;
+//! # lets_expect! { #method
+//! expect(5) {
+//!     to be_between_one_and_ten {
+//!         both(be_greater_than(1), be_less_than(10)),
+//!         not(equal(4)),
+//!         either(equal(5), be_greater_than(100)),
+//!         one_of([equal(1), equal(3), equal(5)])
+//!     }
+//! }
+//! # }
+//! # }
+//! # tests::expect_five::to_be_between_one_and_ten().unwrap();
+//! ```
+//!
+//! ### Snapshots
+//!
+//! `match_snapshot` compares the pretty-printed (`{:#?}`) subject with a snapshot file stored in the `snapshots` directory of your crate.
+//! The file is named after the test, e.g. `my_crate__tests__expect_users__when_admin__to_match_snapshot.snap`. Snapshots are only written when the tests
+//! run with `LETS_EXPECT_UPDATE=1`, which records the missing snapshots and rewrites the existing ones. Otherwise a missing snapshot fails the test,
+//! so that a deleted or renamed snapshot doesn't go unnoticed. When the value differs from the snapshot a diff is shown.
+//! `match_snapshot` can't be used in a property-based `when` since the values change every run.
+//!
+//! ```no_run
+//! # mod tests {
+//! # use lets_expect::*;
+//! # lets_expect! { #method
+//! # let users = vec!["admin", "guest"];
+//! expect(users.clone()) as users {
+//!     to match_snapshot
+//!
+//!     when(users = vec!["admin"]) as admin {
+//!         to have(first()) match_snapshot
+//!     }
+//! }
+//! # }
+//! # }
 //! ```
 //!
 //! ### Custom assertions
@@ -1103,6 +1699,7 @@
 //!
 //! ```
 //! # use lets_expect::lets_expect;
+//! # #[cfg(feature = "tokio")]
 //! lets_expect! { #tokio_test
 //! }
 //! ```
@@ -1112,6 +1709,7 @@
 //! Here's an example of a test using Tokio:
 //!
 //! ```
+//! # #[cfg(feature = "tokio")]
 //! # mod tests {
 //! # use lets_expect::*;
 //! # lets_expect! { #method_async
@@ -1125,6 +1723,7 @@
 //! }
 //! # }
 //! # }
+//! # #[cfg(feature = "tokio")]
 //! # tokio_test::block_on(async { tests::expect_await_spawned::to_match_pattern().await.unwrap() });
 //! ```
 //!
@@ -1138,8 +1737,6 @@
 //!
 //! * rust-analyzer's auto-import doesn't seem to work well from inside of macros. It might be necessary to manually add `use` statements for types from outside of the module.
 //! * Syntax highlighting doesn't work with `lets_expect` syntax. Currently there's no way for Rust macros to export their syntax to language tools.
-//! * Shared contexts (similar to [RSpec](https://relishapp.com/rspec/rspec-core/docs/example-groups/shared-context)) seem to be impossible to implement without
-//!   [eager macro expansion](https://rustc-dev-guide.rust-lang.org/macro-expansion.html#eager-expansion).
 //!
 //! ## Debugging
 //!
@@ -1164,10 +1761,12 @@
     clippy::needless_for_each,
     clippy::todo
 )]
+#![allow(clippy::test_attr_in_doctest)]
 
 pub use std::panic;
 
 pub use lets_expect_macro::lets_expect;
+pub use lets_expect_macro::lets_expect_shared;
 
 pub use lets_expect_core::execution::executed_assertion::ExecutedAssertion;
 pub use lets_expect_core::execution::executed_expectation::ExecutedExpectation;
@@ -1176,6 +1775,8 @@ pub use lets_expect_core::execution::test_failure::TestFailure;
 pub use lets_expect_core::execution::test_result::test_result_from_cases;
 pub use lets_expect_core::execution::test_result::TestResult;
 
+pub use lets_expect_core::property::generators;
+
 pub use lets_expect_core::assertions::assertion_error::AssertionError;
 pub use lets_expect_core::assertions::assertion_result::AssertionResult;
 
@@ -1183,3 +1784,17 @@ pub use lets_expect_assertions::assertions::*;
 
 #[cfg(feature = "tokio")]
 pub use tokio;
+
+/// Used by the code `lets_expect!` generates, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use lets_expect_core::assertions::binding::{Binding, CopyBinding, RefBinding};
+    pub use lets_expect_core::execution::around::run_around;
+    pub use lets_expect_core::execution::current_test::CurrentTest;
+    pub use lets_expect_core::execution::expected_panic::catch_expected_panic;
+    pub use lets_expect_core::execution::module_hooks::{AfterAllGuard, ModuleHooks};
+    pub use lets_expect_core::execution::tags::skipped_by_tags;
+    pub use lets_expect_core::execution::test_panic::{test_cases_with_panics, Phase, TestPanic};
+    pub use lets_expect_core::execution::test_result::pending_test_result;
+    pub use lets_expect_core::property::run_property::run_property;
+}
@@ -0,0 +1,67 @@
+use std::{env, process::Command};
+
+mod hooks {
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(1 + 1) as sum {
+            before_all {
+                println!("before_all");
+            }
+
+            after_all {
+                println!("after_all");
+            }
+
+            to equal(2)
+            xto be_greater_than(1)
+        }
+    }
+}
+
+const CHILD_VARIABLE: &str = "LETS_EXPECT_AFTER_ALL_CHILD";
+
+/// Runs the tests of `hooks` in a new process of this binary, returning what they printed.
+fn run_hooks(arguments: &[&str]) -> String {
+    let output = Command::new(env::current_exe().unwrap())
+        .args(["hooks::", "--nocapture", "--test-threads=1"])
+        .args(arguments)
+        .env(CHILD_VARIABLE, "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn position(output: &str, text: &str) -> usize {
+    output
+        .find(text)
+        .unwrap_or_else(|| panic!("Expected {:?} in {}", text, output))
+}
+
+// The hooks are checked in separate processes, `after_all` can run when the process exits.
+#[test]
+fn after_all_runs_once_with_or_without_ignored_tests() {
+    if env::var(CHILD_VARIABLE).is_ok() {
+        return;
+    }
+
+    let with_ignored = run_hooks(&["--include-ignored"]);
+    assert_eq!(
+        with_ignored.matches("after_all").count(),
+        1,
+        "{}",
+        with_ignored
+    );
+    assert!(position(&with_ignored, "after_all") < position(&with_ignored, "test result"));
+
+    let without_ignored = run_hooks(&[]);
+    assert_eq!(
+        without_ignored.matches("after_all").count(),
+        1,
+        "{}",
+        without_ignored
+    );
+    assert!(position(&without_ignored, "after_all") > position(&without_ignored, "test result"));
+}
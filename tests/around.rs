@@ -0,0 +1,103 @@
+use std::{cell::RefCell, sync::Mutex};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+thread_local! {
+    static LOG: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+pub fn log(entry: &'static str) {
+    LOG.with(|log| log.borrow_mut().push(entry));
+}
+
+pub fn take_log() -> Vec<&'static str> {
+    LOG.with(|log| log.take())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(LOCK.try_lock().is_err()) as locked {
+            around |run| {
+                let _guard = LOCK.lock().unwrap();
+                run();
+            }
+
+            to be_true
+
+            when(x in [1, 2]) {
+                expect(x) to be_greater_than(0)
+            }
+
+            when(s in string_of(0..3)) {
+                let length = s.len();
+
+                expect(length) to be_less_than(16)
+            }
+        }
+
+        expect(1) as one {
+            around |_run| {}
+
+            #[should_panic(expected = "`around` should call the function running the test")]
+            to equal(1)
+        }
+    }
+
+    mod methods {
+        use super::*;
+
+        lets_expect! { #method
+            expect({ log("subject"); 1 }) as logged {
+                let value = { log("let"); 1 };
+
+                around |run| {
+                    log("outer start");
+                    run();
+                    log("outer end");
+                }
+
+                before {
+                    log("before");
+                }
+
+                after {
+                    log("after");
+                }
+
+                when(y = 1) {
+                    around |run| {
+                        log("inner start");
+                        run();
+                        log("inner end");
+                    }
+
+                    to equal(value * y)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_arounds_are_nested_outer_to_inner() {
+        take_log();
+        methods::expect_logged::when_y_is_one::to_equal_value_times_y().unwrap();
+
+        assert_eq!(
+            take_log(),
+            vec![
+                "outer start",
+                "inner start",
+                "let",
+                "before",
+                "subject",
+                "after",
+                "inner end",
+                "outer end"
+            ]
+        );
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(a + b) as sum {
+            let a = 1;
+            let b = 2;
+
+            #[should_panic]
+            to equal(4)
+
+            #[should_panic(expected = "Received")]
+            when(b = 4) to equal(6)
+
+            #[ignore]
+            to equal(3)
+
+            #[cfg(any())]
+            to equal(this_function_does_not_exist())
+
+            #[allow(clippy::identity_op)]
+            when(a = 1 * 1) to equal(3)
+        }
+
+        #[should_panic]
+        expect(numbers.len()) as panicking_length {
+            let numbers = [1, 2, 3];
+
+            to equal(2)
+            to be_greater_than(5)
+        }
+
+        #[cfg(any())]
+        expect(this_function_does_not_exist()) {
+            to equal(1)
+        }
+
+        #[cfg(any())]
+        when(x = this_function_does_not_exist()) {
+            expect(x) to equal(1)
+        }
+    }
+
+    mod methods {
+        use lets_expect::lets_expect;
+
+        lets_expect! { #method
+            #[should_panic]
+            expect(1) as one to equal(2)
+        }
+    }
+
+    #[test]
+    fn test_should_panic_is_ignored_in_methods() {
+        assert!(methods::expect_one::to_equal_two().is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static BEFORE_ALL_RUNS: AtomicUsize = AtomicUsize::new(0);
+static AFTER_ALL_RUNS: AtomicUsize = AtomicUsize::new(0);
+static FLAKY_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+pub fn build_index(size: usize) -> Vec<usize> {
+    BEFORE_ALL_RUNS.fetch_add(1, Ordering::SeqCst);
+    (0..size).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lets_expect::lets_expect;
+    use std::{panic, thread};
+
+    lets_expect! {
+        expect(index.len()) as index_length {
+            before_all index: Vec<usize> {
+                (0..100).collect()
+            }
+
+            to equal(100)
+
+            when(offset = 10) {
+                before_all shifted: Vec<usize> {
+                    index.iter().map(|value| value + 1).collect()
+                }
+
+                expect(shifted[offset]) as shifted_value to equal(11)
+                expect(index[offset]) as value to equal(10)
+            }
+        }
+    }
+
+    mod methods {
+        use super::*;
+
+        lets_expect! { #method
+            expect(index.len()) as index_length {
+                before_all index: Vec<usize> {
+                    build_index(100)
+                }
+
+                before_all {
+                    assert_eq!(BEFORE_ALL_RUNS.load(Ordering::SeqCst), 1);
+                }
+
+                after_all {
+                    AFTER_ALL_RUNS.fetch_add(1, Ordering::SeqCst);
+                }
+
+                to equal(100)
+                to be_greater_than(10)
+
+                when(offset = 10) {
+                    expect(index[offset]) as value to equal(10)
+                }
+            }
+        }
+    }
+
+    mod flaky {
+        use super::*;
+
+        lets_expect! { #method
+            expect(value.len()) as flaky_value {
+                before_all value: String {
+                    if FLAKY_RUNS.fetch_add(1, Ordering::SeqCst) == 0 {
+                        panic!("The first run fails");
+                    }
+                    "ok".to_string()
+                }
+
+                to equal(2)
+            }
+        }
+    }
+
+    #[test]
+    fn test_before_all_runs_again_after_a_panic() {
+        let first = panic::catch_unwind(flaky::expect_flaky_value::to_equal_two);
+        assert!(matches!(first, Err(_) | Ok(Err(_))));
+
+        assert!(flaky::expect_flaky_value::to_equal_two().is_ok());
+        assert_eq!(FLAKY_RUNS.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_hooks_run_once_for_tests_running_in_parallel() {
+        let threads = vec![
+            thread::spawn(|| methods::expect_index_length::to_equal_onehundred().unwrap()),
+            thread::spawn(|| methods::expect_index_length::to_be_greater_than_ten().unwrap()),
+            thread::spawn(|| {
+                methods::expect_index_length::when_offset_is_ten::expect_value::to_equal_ten()
+                    .unwrap()
+            }),
+        ];
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(BEFORE_ALL_RUNS.load(Ordering::SeqCst), 1);
+        assert_eq!(AFTER_ALL_RUNS.load(Ordering::SeqCst), 1);
+    }
+}
@@ -45,3 +45,72 @@ mod tests {
         }
     }
 }
+
+#[cfg(test)]
+mod afters_after_panics {
+    use lets_expect::lets_expect;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static AFTERS: AtomicUsize = AtomicUsize::new(0);
+    static AFTERS_USING_PANICKING_LET: AtomicUsize = AtomicUsize::new(0);
+
+    fn failing() -> i32 {
+        panic!("failed")
+    }
+
+    lets_expect! { #method
+        after {
+            AFTERS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        expect(failing()) as panicking_subject { to equal(1) }
+
+        when panicking_let {
+            let value = failing();
+
+            after {
+                AFTERS_USING_PANICKING_LET.fetch_add(value as usize, Ordering::SeqCst);
+            }
+
+            expect(value) { to equal(1) }
+        }
+
+        when panicking_before {
+            before {
+                failing();
+            }
+
+            expect(1) { to equal(1) }
+        }
+    }
+
+    #[test]
+    fn afters_run_when_the_test_panics() {
+        let tests: [fn() -> Result<(), lets_expect::TestFailure>; 3] = [
+            expect_panicking_subject::to_equal_one,
+            when_panicking_let::expect_value::to_equal_one,
+            when_panicking_before::expect_one::to_equal_one,
+        ];
+
+        for test in tests {
+            assert!(test().is_err());
+        }
+
+        assert_eq!(AFTERS.load(Ordering::SeqCst), 3);
+        assert_eq!(AFTERS_USING_PANICKING_LET.load(Ordering::SeqCst), 0);
+    }
+}
+
+#[cfg(test)]
+mod panicking_after {
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        after {
+            panic!("after panicked");
+        }
+
+        #[should_panic(expected = "after panicked")]
+        expect(1) { to equal(1) }
+    }
+}
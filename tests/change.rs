@@ -4,6 +4,7 @@ mod point;
 mod tests {
     use crate::point::Point;
     use lets_expect::lets_expect;
+    use std::time::{Duration, Instant};
 
     lets_expect! {
         expect(point.x = 5) {
@@ -13,6 +14,32 @@ mod tests {
                 change(point.x) { from(1), to(5), by(4) },
                 not_change(point.y)
             }
+
+            to move_right {
+                change(point.x) { increase, by_at_least(1), by_at_most(4), by_between(3, 5) }
+            }
+        }
+
+        expect(point.y = -3) {
+            let mut point = Point { x: 1, y: 2 };
+
+            to move_down {
+                change(point.y) { decrease, by_between(-5, -1) }
+            }
+        }
+
+        expect(std::thread::sleep(Duration::from_millis(10))) as sleeping {
+            to take_time {
+                change(Instant::now()) by_at_least(Duration::from_millis(10))
+            }
+        }
+
+        expect(temperature += 0.3) as warming {
+            let mut temperature = 21.5;
+
+            to warm_up_slightly {
+                change(temperature) by_approximately(0.3, 1e-9)
+            }
         }
     }
 }
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use lets_expect::lets_expect;
+    use std::collections::{BTreeMap, HashSet};
+
+    lets_expect! {
+        expect(vec![1, 2, 3]) as numbers {
+            to contain_numbers {
+                contain(2),
+                contain_all_of([3, 1]),
+                contain_exactly([1, 2, 3]),
+                contain_in_any_order([3, 2, 1]),
+                have_len(3)
+            }
+        }
+
+        expect(Vec::<u8>::new()) as empty_vec {
+            to be_empty
+        }
+
+        expect(HashSet::from(["a", "b"])) as set {
+            to contain_letters {
+                contain("a"),
+                contain_in_any_order(["b", "a"])
+            }
+        }
+
+        expect(BTreeMap::from([(1, "one"), (2, "two")])) as map {
+            to contain_entries {
+                contain((1, "one")),
+                contain_exactly([(1, "one"), (2, "two")]),
+                have_len(2)
+            }
+        }
+
+        expect("Hello") as greeting {
+            to contain("ell")
+        }
+    }
+}
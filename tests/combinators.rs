@@ -0,0 +1,17 @@
+#[cfg(test)]
+mod tests {
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(a) {
+            when(a = 5) {
+                to be_in_range {
+                    both(be_greater_than(1), be_less_than(10)),
+                    not(equal(4)),
+                    either(equal(5), be_greater_than(100)),
+                    one_of([equal(1), equal(3), equal(5)])
+                }
+            }
+        }
+    }
+}
@@ -2,6 +2,13 @@
 enum Response {
     UserCreated,
     ValidationFailed(&'static str),
+    Ok(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Event {
+    Created { id: u32, name: &'static str },
+    Deleted { id: u32 },
 }
 
 #[cfg(test)]
@@ -24,5 +31,27 @@ mod tests {
                 not_match_pattern!(Response::ValidationFailed("email2"))
             }
         }
+
+        expect(Event::Created { id: 7, name: "post" }) as created {
+            to match_created_with_positive_id {
+                match_pattern!(Event::Created { id, .. } if id > 0),
+                not_match_pattern!(Event::Created { id, .. } | Event::Deleted { id } if id > 10)
+            }
+
+            to match_pattern!(Event::Created { id, name } if id > 5) and {
+                have(name.len()) equal(4),
+                have(id) equal(7)
+            }
+        }
+
+        expect(Event::Deleted { id: 3 }) as deleted {
+            to not_match_pattern!(Event::Created { .. })
+        }
+
+        expect(Response::Ok("abc".to_string())) as ok_response {
+            to match_pattern!(Response::Ok(body) if body.len() == 3)
+
+            to match_pattern!(Response::Ok(body)) and have(body.len()) equal(3)
+        }
     }
 }
@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(1.0e15_f64 + 0.1) as large_sum {
+            to be_approximately(1.0e15, 1e-12)
+            to be_within_ulps(1.0e15, 1)
+            to not_be_close {
+                not(be_approximately(1.1e15, 1e-3))
+            }
+        }
+
+        expect(0.1_f32 * 3.0) as small_product {
+            to be_approximately(0.3, 1e-6)
+            to be_within_ulps(0.3, 1)
+            to be_finite
+        }
+
+        expect(f32::NAN) as nan {
+            to be_nan
+            to not_be_finite {
+                not(be_finite)
+            }
+            to not_be_approximately_itself {
+                not(be_approximately(f32::NAN, 1.0))
+            }
+        }
+
+        expect(-1.0 / 0.0) as negative_infinity {
+            to be_infinite
+            to be_approximately(f64::NEG_INFINITY, 0.0)
+        }
+    }
+}
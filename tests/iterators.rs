@@ -0,0 +1,16 @@
+#[cfg(test)]
+mod tests {
+    use lets_expect::lets_expect;
+    use std::collections::HashMap;
+
+    lets_expect! {
+        expect(HashMap::from([(1, "one"), (2, "two"), (3, "three")])) as map {
+            to have(mut keys()) all(be_greater_than(0))
+            to have(mut values()) any(equal("two"))
+            to have(mut keys()) none(be_greater_than(3))
+            to have(mut keys()) exactly(2, be_greater_than(1))
+            to have(mut keys()) at_least(1, be_less_than(2))
+            to have(mut values()) at_most(1, equal("one"))
+        }
+    }
+}
@@ -2,6 +2,9 @@
 mod tests {
     use lets_expect::lets_expect;
 
+    #[derive(Debug, PartialEq)]
+    struct NotFound(u16);
+
     lets_expect! {
         expect(panic!("I panicked!")) {
             to panic
@@ -10,5 +13,14 @@ mod tests {
         expect(true) {
             to not_panic
         }
+
+        expect(panic!("index {} out of bounds", 5)) as formatted_message {
+            to panic_with("5 out of bounds")
+            to panic_with("index 5")
+        }
+
+        expect(std::panic::panic_any(NotFound(404))) as custom_payload {
+            to panic_with_payload::<NotFound>(equal(NotFound(404)))
+        }
     }
 }
@@ -0,0 +1,54 @@
+use std::{
+    panic,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc, Barrier,
+    },
+    thread,
+};
+
+use lets_expect::__private::catch_expected_panic;
+
+static HOOK_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+fn hook_calls() -> usize {
+    HOOK_CALLS.load(Ordering::SeqCst)
+}
+
+// The only test in this binary, other tests can't panic while the hook is being checked.
+#[test]
+fn expected_panics_keep_the_previous_panic_hook() {
+    panic::set_hook(Box::new(|_| {
+        HOOK_CALLS.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    assert!(catch_expected_panic(|| panic!("expected")).is_err());
+    assert_eq!(hook_calls(), 0);
+
+    assert!(panic::catch_unwind(|| panic!("not expected")).is_err());
+    assert_eq!(hook_calls(), 1);
+
+    let capturing = Arc::new(Barrier::new(2));
+    let (done, finished) = mpsc::channel();
+    let capture = {
+        let capturing = Arc::clone(&capturing);
+        thread::spawn(move || {
+            catch_expected_panic(move || {
+                capturing.wait();
+                finished.recv().unwrap();
+                panic!("expected on another thread");
+            })
+        })
+    };
+
+    capturing.wait();
+    assert!(panic::catch_unwind(|| panic!("not expected while capturing")).is_err());
+    assert_eq!(hook_calls(), 2);
+
+    done.send(()).unwrap();
+    assert!(capture.join().unwrap().is_err());
+    assert_eq!(hook_calls(), 2);
+
+    assert!(panic::catch_unwind(|| panic!("not expected after capturing")).is_err());
+    assert_eq!(hook_calls(), 3);
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use lets_expect::{lets_expect, TestFailure};
+
+    fn failing(message: &str) -> i32 {
+        panic!("{}", message)
+    }
+
+    lets_expect! { #method
+        expect(failing("subject failed")) as panicking_subject { to equal(1) }
+
+        expect(1) as panicking_assertion { to make(failing("assertion failed")) equal(1) }
+
+        when panicking_let {
+            let value = failing("let failed");
+
+            expect(value) { to equal(1) }
+        }
+
+        when panicking_before {
+            before {
+                failing("before failed");
+            }
+
+            expect(1) { to equal(1) }
+        }
+
+        when panicking_after {
+            after {}
+
+            after {
+                failing("after failed");
+            }
+
+            expect(1) { to equal(1) }
+        }
+
+        story panicking_story {
+            expect(1) to equal(1)
+
+            failing("story failed");
+        }
+    }
+
+    type Test = fn() -> Result<(), TestFailure>;
+
+    fn failure(test: Test) -> String {
+        format!("{:?}", test().expect_err("Expected the test to fail"))
+    }
+
+    #[test]
+    fn panics_are_reported_with_the_phase_they_happened_in() {
+        let tests: [(Test, &str, &str); 6] = [
+            (
+                expect_panicking_subject::to_equal_one,
+                "subject",
+                "subject failed",
+            ),
+            (
+                expect_panicking_assertion::to_make_failing_string_equal_one,
+                "assertion",
+                "assertion failed",
+            ),
+            (
+                when_panicking_let::expect_value::to_equal_one,
+                "let value",
+                "let failed",
+            ),
+            (
+                when_panicking_before::expect_one::to_equal_one,
+                "before block 1",
+                "before failed",
+            ),
+            (
+                when_panicking_after::expect_one::to_equal_one,
+                "after block 2",
+                "after failed",
+            ),
+            (panicking_story, "story", "story failed"),
+        ];
+
+        for (test, phase, message) in tests {
+            let failure = failure(test);
+
+            assert!(failure.contains("Panicked in"), "{}", failure);
+            assert!(failure.contains(phase), "{}", failure);
+            assert!(failure.contains(message), "{}", failure);
+        }
+    }
+
+    #[test]
+    fn panics_are_reported_under_the_expect_and_when_header() {
+        let failure = failure(when_panicking_let::expect_value::to_equal_one);
+
+        assert!(failure.contains("Expect"), "{}", failure);
+        assert!(failure.contains("value"), "{}", failure);
+        assert!(failure.contains("When"), "{}", failure);
+        assert!(failure.contains("panicking_let"), "{}", failure);
+    }
+}
@@ -0,0 +1,47 @@
+fn not_implemented_yet() -> i32 {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(a + b) as sum {
+            let a = 1;
+            let b = 2;
+
+            pending to equal(4)
+
+            xto equal(5)
+
+            xwhen(b = 3) to equal(5)
+        }
+
+        expect(not_implemented_yet()) {
+            pending to equal(1)
+        }
+
+        xexpect(not_implemented_yet()) as skipped {
+            to equal(1)
+        }
+    }
+
+    mod methods {
+        use lets_expect::lets_expect;
+
+        lets_expect! { #method
+            expect(1 + 1) as sum {
+                pending to equal(2)
+            }
+        }
+    }
+
+    #[test]
+    fn test_passing_pending_test_fails() {
+        let failure = format!("{:?}", methods::expect_sum::to_equal_two().unwrap_err());
+
+        assert!(failure.contains("to_equal_two passes, remove pending"));
+    }
+}
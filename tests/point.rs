@@ -29,6 +29,7 @@ impl Debug for Point {
     }
 }
 
+#[allow(dead_code)]
 #[derive(PartialEq)]
 pub struct Segment {
     pub start: Point,
@@ -0,0 +1,100 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static BEFORE_RUNS: AtomicUsize = AtomicUsize::new(0);
+static AFTER_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+fn reverse(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(reverse(&reverse(&s))) as reversed_twice {
+            when(s in string_of(0..20)) to equal(s.clone())
+        }
+
+        expect(a.wrapping_add(b)) as wrapping_sum {
+            when(a: u8 in any(), b: u8 in any()) to equal(b.wrapping_add(a))
+        }
+
+        expect(x) {
+            when(x in between(-5, 5)) to be_between(-5, 5)
+        }
+
+        expect(doubled) {
+            let doubled = x * 2;
+            let x = 0;
+
+            when(x: i32 in between(0, 1000), y = 2) to equal(x * y)
+        }
+
+        expect(v.len()) as length {
+            when(v in vec_of(any::<bool>(), 1..=3)) to be_between(1, 3)
+        }
+    }
+
+    mod failing {
+        use super::*;
+
+        lets_expect! { #method
+            expect(x) {
+                when(x: u32 in any()) to be_less_than(1000)
+            }
+
+            expect(y) as counted {
+                before {
+                    BEFORE_RUNS.fetch_add(1, Ordering::SeqCst);
+                }
+
+                after {
+                    AFTER_RUNS.fetch_add(1, Ordering::SeqCst);
+                }
+
+                when(y: u32 in any()) to be_less_than(1000)
+            }
+
+            expect(s.chars().count()) as char_count {
+                when(s in string_of(..)) to be_less_than(3)
+            }
+        }
+    }
+
+    #[test]
+    fn test_failure_reports_minimal_counterexample() {
+        let failure = format!(
+            "{:?}",
+            failing::expect_x::when_x_is_any::to_be_less_than_onethousand().unwrap_err()
+        );
+
+        assert!(failure.contains("Minimal counterexample:"));
+        assert!(failure.contains("x = ") && failure.contains("1000"));
+        assert!(failure.contains("Run with LETS_EXPECT_SEED="));
+    }
+
+    #[test]
+    fn test_failure_shrinks_strings() {
+        let failure = format!(
+            "{:?}",
+            failing::expect_char_count::when_s_is_string_of_range::to_be_less_than_three()
+                .unwrap_err()
+        );
+
+        assert!(failure.contains("\"aaa\""));
+    }
+
+    #[test]
+    fn test_before_and_after_run_for_every_case() {
+        let before_runs = BEFORE_RUNS.load(Ordering::SeqCst);
+        let _ = failing::expect_counted::when_y_is_any::to_be_less_than_onethousand();
+
+        assert!(BEFORE_RUNS.load(Ordering::SeqCst) - before_runs > 1);
+        assert_eq!(
+            BEFORE_RUNS.load(Ordering::SeqCst),
+            AFTER_RUNS.load(Ordering::SeqCst)
+        );
+    }
+}
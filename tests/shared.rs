@@ -0,0 +1,138 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stack {
+    items: Vec<i32>,
+}
+
+impl Stack {
+    pub fn new(items: Vec<i32>) -> Self {
+        Self { items }
+    }
+
+    pub fn push(&mut self, item: i32) {
+        self.items.push(item);
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+lets_expect::lets_expect_shared! {
+    behaves_like_a_sized_collection {
+        let expected_len = 3;
+
+        to have(len()) equal(expected_len)
+    }
+
+    behaves_like_a_stack {
+        include_shared!(behaves_like_a_sized_collection)
+
+        expect(collection.push(1)) as pushing {
+            let mut collection = collection.clone();
+
+            to change(collection.len()) { from(expected_len), to(expected_len + 1) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        shared a_collection {
+            let expected_len = 3;
+
+            to have(len()) equal(expected_len)
+        }
+
+        shared a_growing_collection {
+            behaves_like a_collection
+
+            expect(collection.push(1)) as pushing {
+                let mut collection = collection.clone();
+
+                to change(collection.len()) { from(expected_len), to(expected_len + 1) }
+            }
+        }
+
+        expect(collection.clone()) as stack {
+            let collection = Stack::new(vec![1, 2, 3]);
+
+            behaves_like a_growing_collection
+
+            when(collection = Stack::new(vec![])) as empty {
+                let expected_len = 0;
+
+                behaves_like a_growing_collection
+            }
+        }
+
+        expect(&messages) {
+            let mut messages: Vec<&str> = Vec::new();
+
+            shared with_a_message {
+                before {
+                    messages.push("first");
+                }
+
+                after {
+                    messages.clear();
+                }
+            }
+
+            behaves_like with_a_message
+
+            before {
+                messages.push("second");
+            }
+
+            to equal(vec!["first", "second"])
+        }
+
+        expect(collection.clone()) as included_stack {
+            let collection = Stack::new(vec![1, 2, 3]);
+
+            include_shared!(behaves_like_a_stack)
+
+            when(collection = Stack::new(vec![1])) as with_one_item {
+                let expected_len = 1;
+
+                include_shared!(behaves_like_a_stack)
+            }
+        }
+    }
+
+    mod with_attributes {
+        use super::*;
+        use lets_expect::lets_expect;
+
+        lets_expect! {
+            #[allow(clippy::redundant_clone)]
+            expect(collection.clone()) as attributed_stack {
+                let collection = Stack::new(vec![1, 2, 3]);
+
+                include_shared!(behaves_like_a_stack)
+            }
+        }
+    }
+
+    mod with_tags {
+        use super::*;
+        use lets_expect::lets_expect;
+
+        lets_expect! {
+            #tag(collections)
+            expect(collection.clone()) as tagged_stack {
+                let collection = Stack::new(vec![1, 2, 3]);
+
+                include_shared!(behaves_like_a_stack)
+            }
+        }
+    }
+}
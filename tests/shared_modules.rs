@@ -0,0 +1,57 @@
+#[derive(Clone, Debug, Default)]
+pub struct Counter {
+    count: u32,
+}
+
+impl Counter {
+    pub fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+#[macro_use]
+mod shared_contexts {
+    lets_expect::lets_expect_shared! {
+        behaves_like_a_counter {
+            to have(count()) equal(initial)
+
+            expect(counter.increment()) as incrementing {
+                let mut counter = counter.clone();
+
+                to change(counter.count()) { from(initial), to(initial + 1) }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(counter.clone()) as new_counter {
+            let counter = Counter::default();
+            let initial = 0;
+
+            include_shared!(behaves_like_a_counter)
+        }
+    }
+
+    mod nested {
+        use super::*;
+
+        lets_expect! {
+            expect(counter.clone()) as incremented_counter {
+                let counter = Counter { count: 1 };
+                let initial = 1;
+
+                include_shared!(behaves_like_a_counter)
+            }
+        }
+    }
+}
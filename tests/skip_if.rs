@@ -0,0 +1,63 @@
+use std::env;
+
+fn tool_installed() -> bool {
+    env::var("LETS_EXPECT_TOOL_THAT_IS_NOT_INSTALLED").is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(a + b) as sum {
+            let a = 1;
+            let b = 2;
+
+            skip_if(!tool_installed(), "the tool is not installed")
+
+            to equal(4)
+
+            when(b = 3) to equal(5)
+        }
+
+        expect(a + b) as not_skipped_sum {
+            let a = 1;
+            let b = 2;
+
+            skip_if(tool_installed(), "the tool is installed")
+
+            to equal(3)
+        }
+    }
+
+    mod methods {
+        use super::*;
+        use lets_expect::lets_expect;
+
+        lets_expect! { #method
+            expect(1 + 1) as sum {
+                skip_if(false, "never")
+                skip_if(true, "always")
+
+                to equal(3)
+            }
+
+            expect(1 + 1) as not_skipped_sum {
+                skip_if(tool_installed(), "the tool is installed")
+
+                to equal(3)
+            }
+        }
+    }
+
+    #[test]
+    fn test_skipped_test_passes() {
+        assert!(methods::expect_sum::to_equal_three().is_ok());
+    }
+
+    #[test]
+    fn test_test_that_is_not_skipped_runs() {
+        assert!(methods::expect_not_skipped_sum::to_equal_three().is_err());
+    }
+}
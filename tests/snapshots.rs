@@ -0,0 +1,24 @@
+mod point;
+
+#[cfg(test)]
+mod tests {
+    use crate::point::Point;
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect(points.clone()) as points {
+            let points = vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+
+            to match_snapshot
+
+            when(points = vec![Point { x: 5, y: 6 }]) as single_point {
+                to have(first()) match_snapshot
+            }
+
+            to look_the_same_twice {
+                match_snapshot,
+                have(len()) match_snapshot
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        expect("Hello, world!") {
+            to be_a_greeting {
+                contain("world"),
+                contain(','),
+                start_with("Hello"),
+                end_with("!"),
+                have_char_count(13),
+                match_regex(r"^\w+, \w+!$")
+            }
+        }
+
+        expect(String::from(" \t ")) {
+            to be_blank
+        }
+
+        expect(format!("{}-{}", a, b)) {
+            when(a = "left", b = "right") {
+                to match_regex("^left-right$")
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    mod methods {
+        use lets_expect::lets_expect;
+
+        lets_expect! { #method
+            #tag(db)
+            expect(1 + 1) as sum {
+                to equal(3)
+
+                #tag(slow)
+                to be_greater_than(2)
+            }
+
+            expect(1 + 1) as untagged_sum {
+                to equal(3)
+            }
+        }
+    }
+
+    // The only test in this binary, other tests would see the tags it selects.
+    #[test]
+    fn test_tags_select_tests() {
+        env::set_var("LETS_EXPECT_EXCLUDE_TAGS", "slow");
+        assert!(methods::expect_sum::to_equal_three().is_err());
+        assert!(methods::expect_sum::to_be_greater_than_two().is_ok());
+        assert!(methods::expect_untagged_sum::to_equal_three().is_err());
+
+        env::set_var("LETS_EXPECT_TAGS", "db");
+        assert!(methods::expect_sum::to_equal_three().is_err());
+        assert!(methods::expect_untagged_sum::to_equal_three().is_ok());
+
+        env::remove_var("LETS_EXPECT_EXCLUDE_TAGS");
+        assert!(methods::expect_sum::to_be_greater_than_two().is_err());
+
+        env::remove_var("LETS_EXPECT_TAGS");
+        assert!(methods::expect_untagged_sum::to_equal_three().is_err());
+    }
+}
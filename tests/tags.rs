@@ -0,0 +1,17 @@
+#[cfg(test)]
+mod tests {
+    use lets_expect::lets_expect;
+
+    lets_expect! {
+        #tag(fast)
+        expect(a + b) as sum {
+            let a = 1;
+            let b = 2;
+
+            to equal(3)
+
+            #tag(slow, db)
+            when(b = 3) to equal(4)
+        }
+    }
+}
@@ -1,5 +1,17 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Role {
+    Admin,
+    User,
+    Guest,
+}
+
+fn can_delete(role: Role, level: i32) -> bool {
+    role == Role::Admin || (role == Role::User && level >= 2)
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use lets_expect::lets_expect;
 
     lets_expect! {
@@ -40,5 +52,75 @@ mod tests {
         expect(array) {
             when(array = [1, 2, 3]) to equal([1, 2, 3])
         }
+
+        expect(a + b) as sum {
+            when each (a, b, sum) in [(1, 2, 3), (3, 5, 8), (-1, 1, 0)] {
+                to equal(sum)
+            }
+
+            when each (a, b) in [(1, 1), (2, 2)] to equal(a * 2)
+
+            when each ((a, b), sum) in [((2, 3), 5), ((4, 4), 8)] {
+                to equal(sum)
+            }
+        }
+
+        expect(word.len()) as word_length {
+            when each (word, length) in [("one", 3), ("three", 5)] {
+                to equal(length)
+            }
+
+            when each word in ["", "a"] {
+                to be_less_than(2)
+            }
+        }
+
+        expect(word.len()) as word_length_of_unnamed_values {
+            when each word in ["ab", "cd", string_2] {
+                let string_2 = "ef";
+
+                to equal(2)
+            }
+        }
+
+        expect(a + b) as sum_from_file {
+            when each row in file("tests/data/cases.json") {
+                to equal(sum)
+            }
+        }
+
+        expect(word.len()) as word_length_from_file {
+            when each (word, length) in file("tests/data/words.csv") {
+                to equal(length)
+            }
+        }
+
+        expect(word.is_empty()) as word_is_empty_from_file {
+            when each row in file("tests/data/words.csv") {
+                to equal(empty)
+            }
+        }
+
+        expect(can_delete(role, level)) {
+            when(role in [Role::Admin], level in 0..4) {
+                to be_true
+            }
+
+            when(role in [Role::User, Role::Guest], level in -2..=1) {
+                to be_false
+            }
+
+            when(level in [2, 3], role = Role::User) {
+                to be_true
+            }
+        }
+
+        expect(each) as each_is_still_an_identifier {
+            when each {
+                let each = 1;
+
+                to equal(1)
+            }
+        }
     }
 }